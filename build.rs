@@ -1,30 +1,313 @@
 use std::env;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// C-FFI source modules that each get their own generated header under
+/// `include/basis_rs/<module>.h`, instead of one flat dump. Add an entry
+/// here whenever a new `extern "C"` module is introduced.
+const FFI_MODULES: &[&str] = &["ffi"];
 
 fn main() {
-    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let output_dir = PathBuf::from(&crate_dir).join("include");
+    println!("cargo:rerun-if-changed=src/cxx_bridge.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=BASIS_RS_X86_64_AVX2");
+    for module in FFI_MODULES {
+        println!("cargo:rerun-if-changed=src/{module}.rs");
+    }
+
+    // docs.rs (and any environment without a C++17 toolchain) can't run
+    // cbindgen/cxx_build; `doc-only` skips native codegen entirely so the
+    // `cxx_bridge` module still type-checks and docs still render.
+    if cfg!(feature = "doc-only") {
+        return;
+    }
 
-    // Create include directory if it doesn't exist
-    std::fs::create_dir_all(&output_dir).expect("Failed to create include directory");
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let include_dir = PathBuf::from(&out_dir).join("include").join("basis_rs");
+    fs::create_dir_all(&include_dir).expect("Failed to create include directory");
 
-    // Generate C header with cbindgen (for legacy FFI)
     let config = cbindgen::Config::from_file("cbindgen.toml")
         .expect("Failed to read cbindgen.toml");
 
-    cbindgen::Builder::new()
-        .with_crate(&crate_dir)
-        .with_config(config)
-        .generate()
-        .expect("Failed to generate C bindings")
-        .write_to_file(output_dir.join("basis_rs.h"));
+    // One cbindgen pass per module, each scoped to that module's source file
+    // so a consumer that only needs e.g. the column-reader surface can
+    // `#include <basis_rs/ffi.h>` without pulling in the rest.
+    for module in FFI_MODULES {
+        let src = PathBuf::from(&crate_dir).join("src").join(format!("{module}.rs"));
 
-    // Build CXX bridge
-    cxx_build::bridge("src/cxx_bridge.rs")
-        .flag_if_supported("-std=c++17")
-        .compile("basis_rs_cxx");
+        cbindgen::Builder::new()
+            .with_crate(&crate_dir)
+            .with_src(&src)
+            .with_config(config.clone())
+            .generate()
+            .unwrap_or_else(|e| panic!("Failed to generate C bindings for {module}: {e}"))
+            .write_to_file(include_dir.join(format!("{module}.h")));
+    }
 
-    println!("cargo:rerun-if-changed=src/ffi.rs");
-    println!("cargo:rerun-if-changed=src/cxx_bridge.rs");
-    println!("cargo:rerun-if-changed=cbindgen.toml");
+    let libdir = cargo_output_dir(&out_dir);
+    write_pkgconfig(&crate_dir, &include_dir, &libdir, &out_dir);
+    write_cmake_config(&include_dir, &libdir, &out_dir);
+
+    #[cfg(feature = "system-blas")]
+    link_blas();
+
+    #[cfg(feature = "extern-ffi")]
+    generate_extern_bindings();
+
+    // Build CXX bridge, with SIMD flags/sources/defines selected per target.
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let arch_config = arch_config_for(&arch, &os);
+    println!("basis-rs: building CXX bridge with '{}' SIMD configuration", arch_config.name);
+
+    let mut bridge = cxx_build::bridge("src/cxx_bridge.rs");
+    bridge.flag_if_supported("-std=c++17");
+    for flag in arch_config.flags {
+        bridge.flag_if_supported(flag);
+    }
+    for (key, value) in arch_config.defs {
+        bridge.define(key, Some(*value));
+    }
+    for src in arch_config.sources {
+        bridge.file(src);
+        println!("cargo:rerun-if-changed={src}");
+    }
+    bridge.compile("basis_rs_cxx");
+}
+
+/// Per-(arch, os) build configuration for the CXX bridge's native side:
+/// which SIMD flags to pass, which preprocessor defines to set, and which
+/// arch-specific kernel source files (if any) to compile alongside
+/// `cxx_bridge.rs`'s generated glue.
+struct ArchConfig {
+    name: &'static str,
+    sources: &'static [&'static str],
+    flags: &'static [&'static str],
+    defs: &'static [(&'static str, &'static str)],
+}
+
+/// Select the `ArchConfig` for `(arch, os)`, read from
+/// `CARGO_CFG_TARGET_ARCH`/`CARGO_CFG_TARGET_OS`. Only the arch selects the
+/// entry. x86_64 (unless `BASIS_RS_X86_64_AVX2=1`) and the arches listed
+/// without SIMD kernels build with no extra SIMD flags; any other arch
+/// panics with a clear message.
+fn arch_config_for(arch: &str, os: &str) -> ArchConfig {
+    match (arch, os) {
+        // `flag_if_supported` only checks that the *compiler* accepts
+        // `-mavx2 -mfma`, not that the *host running the binary* has an
+        // AVX2-capable CPU; unconditionally baking them in would SIGILL on
+        // pre-Haswell/pre-Excavator x86_64 hosts. `BASIS_RS_X86_64_AVX2=1`
+        // opts in for builds that are known to run only on AVX2 hardware
+        // (e.g. a fleet pinned to modern instance types); the default build
+        // stays on the SSE2 baseline that every x86_64 CPU supports.
+        ("x86_64", _) if env::var("BASIS_RS_X86_64_AVX2").as_deref() == Ok("1") => ArchConfig {
+            name: "x86_64+avx2",
+            sources: &[],
+            flags: &["-mavx2", "-mfma"],
+            defs: &[("BASIS_RS_ARCH_X86_64", "1"), ("BASIS_RS_ARCH_X86_64_AVX2", "1")],
+        },
+        ("x86_64", _) => ArchConfig {
+            name: "x86_64",
+            sources: &[],
+            flags: &[],
+            defs: &[("BASIS_RS_ARCH_X86_64", "1")],
+        },
+        ("aarch64", _) => ArchConfig {
+            name: "aarch64",
+            sources: &[],
+            // NEON is baseline on the armv8-a architecture itself, so this
+            // portable baseline (unlike `-mcpu=native`) is safe to run on
+            // any aarch64 host, not just the machine that built it.
+            flags: &["-march=armv8-a+simd"],
+            defs: &[("BASIS_RS_ARCH_AARCH64", "1")],
+        },
+        // No SIMD kernels tuned for these yet; build without extra flags
+        // rather than refusing to compile on a working toolchain.
+        (other_arch @ ("arm" | "armv7" | "x86" | "riscv64" | "wasm32"), _) => ArchConfig {
+            name: other_arch,
+            sources: &[],
+            flags: &[],
+            defs: &[],
+        },
+        (other_arch, other_os) => panic!(
+            "basis-rs: no SIMD build configuration for target arch '{other_arch}' / os '{other_os}'; \
+             add an ArchConfig entry in build.rs for this target"
+        ),
+    }
+}
+
+/// Walk up from `OUT_DIR` (`<target_dir>/<profile>/build/<pkg>-<hash>/out`)
+/// to the profile directory (`<target_dir>/<profile>`) where Cargo actually
+/// places `libbasis_rs.a`, so the generated `.pc`/`.cmake` files point at a
+/// directory that exists and contains the archive.
+fn cargo_output_dir(out_dir: &str) -> PathBuf {
+    PathBuf::from(out_dir)
+        .parent() // <pkg>-<hash>
+        .and_then(Path::parent) // build
+        .and_then(Path::parent) // <profile>
+        .expect("OUT_DIR has the expected Cargo build-script layout")
+        .to_path_buf()
+}
+
+/// Write `basis_rs.pc` so downstream C/C++ builds can
+/// `pkg-config --cflags --libs basis_rs` instead of hand-wiring paths.
+/// Written under `OUT_DIR` rather than the crate root so a build doesn't
+/// dirty the source checkout; set `PKG_CONFIG_PATH` to `OUT_DIR` to use it.
+fn write_pkgconfig(crate_dir: &str, include_dir: &Path, libdir: &Path, out_dir: &str) {
+    let version = env::var("CARGO_PKG_VERSION").unwrap_or_else(|_| "0.0.0".to_string());
+    let include_root = include_dir
+        .parent()
+        .expect("include/basis_rs always has a parent");
+
+    let pc = format!(
+        "prefix={crate_dir}\n\
+         includedir={include}\n\
+         libdir={libdir}\n\
+         \n\
+         Name: basis_rs\n\
+         Description: Zero-copy Parquet FFI for C/C++ consumers\n\
+         Version: {version}\n\
+         Cflags: -I${{includedir}}\n\
+         Libs: -L${{libdir}} -lbasis_rs\n",
+        crate_dir = crate_dir,
+        include = include_root.display(),
+        libdir = libdir.display(),
+        version = version,
+    );
+
+    fs::write(PathBuf::from(out_dir).join("basis_rs.pc"), pc).expect("Failed to write basis_rs.pc");
+}
+
+/// Write a `basis_rs-config.cmake` exporting an imported target, so
+/// downstream CMake projects can just `find_package(basis_rs)`. Written
+/// under `OUT_DIR`; point `CMAKE_PREFIX_PATH` at it to use it.
+fn write_cmake_config(include_dir: &Path, libdir: &Path, out_dir: &str) {
+    let include_root = include_dir
+        .parent()
+        .expect("include/basis_rs always has a parent");
+
+    let cmake = format!(
+        "add_library(basis_rs::basis_rs STATIC IMPORTED)\n\
+         set_target_properties(basis_rs::basis_rs PROPERTIES\n\
+         \x20\x20IMPORTED_LOCATION \"{libdir}/libbasis_rs.a\"\n\
+         \x20\x20INTERFACE_INCLUDE_DIRECTORIES \"{include_root}\"\n\
+         )\n",
+        libdir = libdir.display(),
+        include_root = include_root.display(),
+    );
+
+    fs::write(PathBuf::from(out_dir).join("basis_rs-config.cmake"), cmake)
+        .expect("Failed to write basis_rs-config.cmake");
+}
+
+/// Lowest/highest BLAS/LAPACK `pkg-config` version this crate has been
+/// validated against. Override via `BASIS_RS_BLAS_MIN_VERSION` /
+/// `BASIS_RS_BLAS_MAX_VERSION` for a newer or vendor-patched system install.
+#[cfg(feature = "system-blas")]
+const DEFAULT_BLAS_MIN_VERSION: &str = "3.8.0";
+#[cfg(feature = "system-blas")]
+const DEFAULT_BLAS_MAX_VERSION: &str = "4.0.0";
+
+/// Parse a dotted version string (`"3.10.1"`) into a comparable tuple,
+/// treating missing/non-numeric components as `0`.
+#[cfg(feature = "system-blas")]
+fn parse_version(v: &str) -> (u32, u32, u32) {
+    let mut parts = v.split('.').map(|p| p.parse::<u32>().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+/// Probe for a system BLAS/LAPACK via pkg-config within
+/// `[min_version, max_version)`, falling back to building the vendored
+/// reference implementation (`vendor/`) via CMake when the system library is
+/// missing or outside that range.
+#[cfg(feature = "system-blas")]
+fn link_blas() {
+    println!("cargo:rerun-if-env-changed=BASIS_RS_BLAS_MIN_VERSION");
+    println!("cargo:rerun-if-env-changed=BASIS_RS_BLAS_MAX_VERSION");
+    println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+
+    let min_version =
+        env::var("BASIS_RS_BLAS_MIN_VERSION").unwrap_or_else(|_| DEFAULT_BLAS_MIN_VERSION.to_string());
+    let max_version =
+        env::var("BASIS_RS_BLAS_MAX_VERSION").unwrap_or_else(|_| DEFAULT_BLAS_MAX_VERSION.to_string());
+
+    // `cargo_metadata(false)`: we want to inspect the probed version and
+    // decide whether to accept it before emitting any link directives.
+    let probed = pkg_config::Config::new()
+        .atleast_version(&min_version)
+        .cargo_metadata(false)
+        .probe("lapack");
+
+    let accepted = match probed {
+        Ok(lib) if parse_version(&lib.version) < parse_version(&max_version) => Some(lib),
+        Ok(lib) => {
+            println!(
+                "cargo:warning=system lapack {} is >= max supported version {max_version}; \
+                 building vendored fallback instead",
+                lib.version,
+            );
+            None
+        }
+        Err(e) => {
+            println!("cargo:warning=no system BLAS/LAPACK >= {min_version} found ({e}); building vendored fallback");
+            None
+        }
+    };
+
+    match accepted {
+        Some(lib) => {
+            for path in &lib.link_paths {
+                println!("cargo:rustc-link-search=native={}", path.display());
+            }
+            for name in &lib.libs {
+                println!("cargo:rustc-link-lib={name}");
+            }
+        }
+        None => {
+            // Panics with a clear diagnostic if the vendored build itself fails.
+            let dst = cmake::build("vendor");
+            println!("cargo:rustc-link-search=native={}", dst.join("lib").display());
+            println!("cargo:rustc-link-lib=static=basis_blas_vendor");
+        }
+    }
+}
+
+/// Generate bindings for an external C market-data/pricing header, so
+/// `src/extern_ffi.rs` can call into an existing native quant library
+/// instead of us hand-writing `extern "C"` blocks for it.
+///
+/// The header path comes from `BASIS_RS_EXTERN_HEADER`; only symbols
+/// matching `BASIS_RS_EXTERN_PREFIX` (default `basis_ext_`) are allowlisted,
+/// to keep the generated surface to just what's relevant.
+#[cfg(feature = "extern-ffi")]
+fn generate_extern_bindings() {
+    println!("cargo:rerun-if-env-changed=BASIS_RS_EXTERN_HEADER");
+    println!("cargo:rerun-if-env-changed=BASIS_RS_EXTERN_PREFIX");
+
+    let header = env::var("BASIS_RS_EXTERN_HEADER").unwrap_or_else(|_| {
+        panic!(
+            "extern-ffi feature enabled but BASIS_RS_EXTERN_HEADER is not set; \
+             point it at the C header to bind"
+        )
+    });
+    let prefix = env::var("BASIS_RS_EXTERN_PREFIX").unwrap_or_else(|_| "basis_ext_".to_string());
+    let allowlist = format!("{prefix}.*");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    bindgen::Builder::default()
+        .header(&header)
+        .allowlist_function(&allowlist)
+        .allowlist_type(&allowlist)
+        .allowlist_var(&allowlist)
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .generate()
+        .unwrap_or_else(|e| panic!("Failed to generate bindings for {header}: {e}"))
+        .write_to_file(PathBuf::from(out_dir).join("extern_bindings.rs"))
+        .expect("Failed to write extern_bindings.rs");
 }
@@ -12,6 +12,8 @@
 //! 4. ReadAllAs<T> done entirely in C++ using column slices
 
 use crate::parquet::{ParquetReader as PolarsReader, ParquetWriter as PolarsWriter};
+use polars::export::arrow::array::{Array, PrimitiveArray};
+use polars::export::arrow::types::NativeType;
 use polars::prelude::*;
 use std::collections::HashMap;
 
@@ -27,15 +29,30 @@ mod ffi {
         Float32,
         String,
         Bool,
-        DateTime, // milliseconds since epoch
+        DateTime, // see ColumnInfo::time_unit/timezone for the real unit/zone
+        Dictionary, // dictionary-encoded (categorical) string column
+        List,
+        Struct,
+        Decimal128, // fixed-point i128, see ColumnInfo::decimal_precision/decimal_scale
+        Date32, // days since the Unix epoch
+        Time64, // nanoseconds since midnight
         Unknown,
     }
 
     /// Information about a column in a Parquet file.
+    ///
+    /// `time_unit` ("ms"/"us"/"ns") and `timezone` are only meaningful for
+    /// `DateTime`/`Time64` columns and are empty strings otherwise.
+    /// `decimal_precision`/`decimal_scale` are only meaningful for
+    /// `Decimal128` columns and are `0` otherwise.
     #[derive(Debug, Clone)]
     struct ColumnInfo {
         name: String,
         dtype: ColumnType,
+        time_unit: String,
+        timezone: String,
+        decimal_precision: u8,
+        decimal_scale: u8,
     }
 
     /// Filter comparison operator shared between Rust and C++.
@@ -57,6 +74,62 @@ mod ffi {
         ptr: usize,
         /// Number of elements in this chunk
         len: usize,
+        /// Bit offset of element 0 within the first byte at `ptr`. Always 0
+        /// except for bit-packed boolean/validity bitmaps
+        /// (`parquet_df_get_bool_chunks`, `parquet_df_get_validity`), where a
+        /// sliced array can start mid-byte; primitive value buffers apply
+        /// their offset to `ptr` directly and never set this.
+        bit_offset: u8,
+    }
+
+    /// A dictionary-encoded (categorical) string column: the distinct values
+    /// plus, per chunk, a zero-copy pointer at the raw `u32` code buffer.
+    /// Codes index into `values`.
+    #[derive(Debug, Clone)]
+    struct DictionaryColumn {
+        values: Vec<String>,
+        codes: Vec<ColumnChunk>,
+    }
+
+    /// A zero-copy `List<primitive>` column: the child values are exposed
+    /// per-chunk (rechunked to one contiguous buffer), and `offsets` is the
+    /// Arrow large-list offsets buffer - row `r` spans
+    /// `values[offsets[r]..offsets[r+1]]`.
+    #[derive(Debug, Clone)]
+    struct ListColumn {
+        values: Vec<ColumnChunk>,
+        offsets: Vec<i64>,
+    }
+
+    /// A single filter clause for `parquet_stream_open`: `column <op> value`.
+    /// `value` is parsed as an int, then a float, then a bool, falling back
+    /// to a string; the column's dtype is not consulted.
+    #[derive(Debug, Clone)]
+    struct StreamFilter {
+        column: String,
+        op: FilterOp,
+        value: String,
+    }
+
+    /// Per-row-group-chunk statistics decoded from the Parquet footer only
+    /// (no column data is read). `has_stats` is false when the file carries
+    /// no statistics for this column chunk; `null_count` is `-1` when the
+    /// writer didn't record one. At most one of `has_min_max_i64`,
+    /// `has_min_max_f64`, `has_min_max_str` is set, matching the column's
+    /// physical type.
+    #[derive(Debug, Clone)]
+    struct ColumnStats {
+        has_stats: bool,
+        null_count: i64,
+        has_min_max_i64: bool,
+        min_i64: i64,
+        max_i64: i64,
+        has_min_max_f64: bool,
+        min_f64: f64,
+        max_f64: f64,
+        has_min_max_str: bool,
+        min_str: String,
+        max_str: String,
     }
 
     extern "Rust" {
@@ -117,16 +190,107 @@ mod ffi {
             df: &ParquetDataFrame,
             column: &str,
         ) -> Result<Vec<ColumnChunk>>;
+        /// Get the raw Int64 backing buffer for a Datetime column. The
+        /// column's actual time unit (ms/us/ns) and timezone are reported in
+        /// `ColumnInfo`, not assumed here.
         fn parquet_df_get_datetime_chunks(
             df: &ParquetDataFrame,
             column: &str,
         ) -> Result<Vec<ColumnChunk>>;
 
+        /// Get the raw `i128` backing buffer for a Decimal column. The
+        /// shared scale (for interpreting the fixed-point value exactly) is
+        /// reported in `ColumnInfo::decimal_scale`.
+        fn parquet_df_get_decimal_chunks(
+            df: &ParquetDataFrame,
+            column: &str,
+        ) -> Result<Vec<ColumnChunk>>;
+
         /// Get string column - returns all strings concatenated with offsets.
         /// This is the only type that requires allocation on read.
         fn parquet_df_get_string_column(df: &ParquetDataFrame, column: &str)
             -> Result<Vec<String>>;
 
+        /// Returns true if `column` is dictionary-encoded (categorical).
+        fn parquet_df_is_dictionary(df: &ParquetDataFrame, column: &str) -> Result<bool>;
+
+        /// Get a dictionary-encoded string column zero-copy: the distinct
+        /// values are materialized once, and the per-chunk `u32` code
+        /// buffers are exposed as raw pointers.
+        fn parquet_df_get_dictionary_column(
+            df: &ParquetDataFrame,
+            column: &str,
+        ) -> Result<DictionaryColumn>;
+
+        /// Get the per-chunk validity (null) bitmap for a column, zero-copy.
+        /// A chunk with no nulls is returned with a null `ptr`; otherwise
+        /// `ptr` points at the bit-packed Arrow null bitmap, matching the
+        /// layout `parquet_df_get_bool_chunks` uses for boolean data.
+        fn parquet_df_get_validity(df: &ParquetDataFrame, column: &str) -> Result<Vec<ColumnChunk>>;
+
+        /// Get a `List<Float64>` column zero-copy (rechunked internally so
+        /// `offsets` indexes one contiguous child buffer). `column` may be a
+        /// dotted `parent.child` path to resolve into a Struct's field.
+        fn parquet_df_get_list_f64(df: &ParquetDataFrame, column: &str) -> Result<ListColumn>;
+        fn parquet_df_get_list_i64(df: &ParquetDataFrame, column: &str) -> Result<ListColumn>;
+        fn parquet_df_get_list_i32(df: &ParquetDataFrame, column: &str) -> Result<ListColumn>;
+
+        /// Get the field names/types of a Struct column, so C++ can
+        /// discover and then address each field via a dotted path on the
+        /// existing primitive/list getters.
+        fn parquet_df_struct_fields(df: &ParquetDataFrame, column: &str) -> Result<Vec<ColumnInfo>>;
+
+        /// Opaque row-group/batch streaming cursor over a Parquet file, so a
+        /// multi-GB file can be consumed in bounded-memory chunks instead of
+        /// materializing the whole file into one DataFrame up front.
+        type ParquetBatchStream;
+
+        /// Open a streaming cursor. `batch_rows` bounds the number of rows
+        /// materialized per `parquet_stream_next` call. `columns` is pushed
+        /// down into the Parquet reader; `filters` are applied to each batch
+        /// after it is read, so they do not skip row groups.
+        fn parquet_stream_open(
+            path: &str,
+            batch_rows: usize,
+            columns: Vec<String>,
+            filters: Vec<StreamFilter>,
+        ) -> Result<Box<ParquetBatchStream>>;
+
+        /// Get the stream's output schema without materializing any rows.
+        fn parquet_stream_schema(stream: &ParquetBatchStream) -> Result<Vec<ColumnInfo>>;
+
+        /// Pull the next batch of up to `batch_rows` rows. Returns `None`
+        /// once the stream is exhausted.
+        fn parquet_stream_next(
+            stream: &mut ParquetBatchStream,
+        ) -> Result<Option<Box<ParquetDataFrame>>>;
+
+        /// Opaque handle onto a Parquet file's footer only - no column data
+        /// is decoded. Lets C++ do its own row-group pruning and cost
+        /// estimation before deciding whether to open a `ParquetDataFrame`.
+        type ParquetMetadata;
+
+        /// Read just the footer (row-group/column-chunk statistics) of a
+        /// Parquet file.
+        fn parquet_metadata_open(path: &str) -> Result<Box<ParquetMetadata>>;
+
+        /// Number of row groups in the file.
+        fn parquet_metadata_num_row_groups(meta: &ParquetMetadata) -> usize;
+
+        /// Number of rows in row group `rg`.
+        fn parquet_metadata_row_group_rows(meta: &ParquetMetadata, rg: usize) -> Result<usize>;
+
+        /// Total uncompressed byte size of row group `rg`, for cost estimation.
+        fn parquet_metadata_row_group_bytes(meta: &ParquetMetadata, rg: usize) -> Result<usize>;
+
+        /// Min/max/null-count statistics for `column` within row group `rg`,
+        /// decoded from the footer without touching the column's data pages.
+        fn parquet_metadata_column_stats(
+            meta: &ParquetMetadata,
+            rg: usize,
+            column: &str,
+        ) -> Result<ColumnStats>;
+
         // ==================== Legacy API (for backward compatibility) ====================
 
         type ParquetReader;
@@ -227,6 +391,15 @@ mod ffi {
             op: FilterOp,
             value: bool,
         );
+
+        /// Sort the result by `column`. Combined with `parquet_query_limit`,
+        /// executed as a true top-K (row-group statistics skipping) rather
+        /// than a full sort.
+        fn parquet_query_order_by(query: &mut ParquetQuery, column: &str, descending: bool);
+
+        /// Cap the result to the first `k` rows (after `order_by`, if set).
+        fn parquet_query_limit(query: &mut ParquetQuery, k: usize);
+
         fn parquet_query_collect(query: Box<ParquetQuery>) -> Result<Box<ParquetReader>>;
 
         /// New: collect into zero-copy DataFrame
@@ -242,18 +415,57 @@ pub struct ParquetDataFrame {
     df: DataFrame,
 }
 
-fn dtype_to_column_type(dtype: &DataType) -> ffi::ColumnType {
+fn time_unit_str(unit: TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Milliseconds => "ms",
+        TimeUnit::Microseconds => "us",
+        TimeUnit::Nanoseconds => "ns",
+    }
+}
+
+/// Build the full `ColumnInfo` (type plus logical-type metadata) for a
+/// column. `time_unit`/`timezone` are only populated for `DateTime`/`Time64`
+/// columns; `decimal_precision`/`decimal_scale` only for `Decimal128`.
+fn column_info_for(name: &str, dtype: &DataType) -> ffi::ColumnInfo {
+    let mut info = ffi::ColumnInfo {
+        name: name.to_string(),
+        dtype: ffi::ColumnType::Unknown,
+        time_unit: String::new(),
+        timezone: String::new(),
+        decimal_precision: 0,
+        decimal_scale: 0,
+    };
+
     match dtype {
-        DataType::Int64 => ffi::ColumnType::Int64,
-        DataType::Int32 => ffi::ColumnType::Int32,
-        DataType::UInt64 => ffi::ColumnType::UInt64,
-        DataType::Float64 => ffi::ColumnType::Float64,
-        DataType::Float32 => ffi::ColumnType::Float32,
-        DataType::String => ffi::ColumnType::String,
-        DataType::Boolean => ffi::ColumnType::Bool,
-        DataType::Datetime(_, _) => ffi::ColumnType::DateTime,
-        _ => ffi::ColumnType::Unknown,
+        DataType::Int64 => info.dtype = ffi::ColumnType::Int64,
+        DataType::Int32 => info.dtype = ffi::ColumnType::Int32,
+        DataType::UInt64 => info.dtype = ffi::ColumnType::UInt64,
+        DataType::Float64 => info.dtype = ffi::ColumnType::Float64,
+        DataType::Float32 => info.dtype = ffi::ColumnType::Float32,
+        DataType::String => info.dtype = ffi::ColumnType::String,
+        DataType::Boolean => info.dtype = ffi::ColumnType::Bool,
+        DataType::Datetime(unit, tz) => {
+            info.dtype = ffi::ColumnType::DateTime;
+            info.time_unit = time_unit_str(*unit).to_string();
+            info.timezone = tz.as_ref().map(|t| t.to_string()).unwrap_or_default();
+        }
+        DataType::Date => info.dtype = ffi::ColumnType::Date32,
+        DataType::Time => {
+            info.dtype = ffi::ColumnType::Time64;
+            info.time_unit = "ns".to_string();
+        }
+        DataType::Decimal(precision, scale) => {
+            info.dtype = ffi::ColumnType::Decimal128;
+            info.decimal_precision = precision.unwrap_or(0) as u8;
+            info.decimal_scale = *scale as u8;
+        }
+        DataType::Categorical(_, _) => info.dtype = ffi::ColumnType::Dictionary,
+        DataType::List(_) => info.dtype = ffi::ColumnType::List,
+        DataType::Struct(_) => info.dtype = ffi::ColumnType::Struct,
+        _ => {}
     }
+
+    info
 }
 
 fn parquet_open(path: &str) -> Result<Box<ParquetDataFrame>, String> {
@@ -286,10 +498,7 @@ fn parquet_df_columns(df: &ParquetDataFrame) -> Vec<ffi::ColumnInfo> {
     df.df
         .get_columns()
         .iter()
-        .map(|col| ffi::ColumnInfo {
-            name: col.name().to_string(),
-            dtype: dtype_to_column_type(col.dtype()),
-        })
+        .map(|col| column_info_for(col.name(), col.dtype()))
         .collect()
 }
 
@@ -306,16 +515,60 @@ fn parquet_df_num_chunks(df: &ParquetDataFrame, column: &str) -> Result<usize, S
     Ok(col.n_chunks())
 }
 
+/// Resolve a column path, accepting a dotted `parent.child` path that walks
+/// into a Struct column's fields (nested structs are resolved recursively).
+/// A plain name with no dots is just a top-level column lookup.
+fn resolve_series(df: &DataFrame, path: &str) -> Result<Series, String> {
+    let mut parts = path.splitn(2, '.');
+    let top = parts.next().unwrap_or(path);
+    let rest = parts.next();
+
+    let series = df
+        .column(top)
+        .map_err(|e| format!("Column '{}' not found: {}", top, e))?
+        .as_materialized_series()
+        .clone();
+
+    match rest {
+        None => Ok(series),
+        Some(child_path) => {
+            let struct_ca = series
+                .struct_()
+                .map_err(|e| format!("Column '{}' is not a Struct: {}", top, e))?;
+            resolve_struct_path(struct_ca, child_path)
+        }
+    }
+}
+
+fn resolve_struct_path(struct_ca: &StructChunked, path: &str) -> Result<Series, String> {
+    let mut parts = path.splitn(2, '.');
+    let field_name = parts.next().unwrap_or(path);
+    let rest = parts.next();
+
+    let field = struct_ca
+        .fields_as_series()
+        .into_iter()
+        .find(|s| s.name().as_str() == field_name)
+        .ok_or_else(|| format!("Struct field '{}' not found", field_name))?;
+
+    match rest {
+        None => Ok(field),
+        Some(child_path) => {
+            let nested = field
+                .struct_()
+                .map_err(|e| format!("Field '{}' is not a Struct: {}", field_name, e))?;
+            resolve_struct_path(nested, child_path)
+        }
+    }
+}
+
 // Macro to generate chunk getter functions for primitive types
 macro_rules! impl_get_chunks {
     ($fn_name:ident, $polars_method:ident, $rust_type:ty) => {
         fn $fn_name(df: &ParquetDataFrame, column: &str) -> Result<Vec<ffi::ColumnChunk>, String> {
-            let col = df
-                .df
-                .column(column)
-                .map_err(|e| format!("Column '{}' not found: {}", column, e))?;
+            let series = resolve_series(&df.df, column)?;
 
-            let ca = col
+            let ca = series
                 .$polars_method()
                 .map_err(|e| format!("Column '{}' type mismatch: {}", column, e))?;
 
@@ -326,6 +579,7 @@ macro_rules! impl_get_chunks {
                     ffi::ColumnChunk {
                         ptr: values.as_ptr() as usize,
                         len: values.len(),
+                        bit_offset: 0,
                     }
                 })
                 .collect();
@@ -345,26 +599,25 @@ fn parquet_df_get_bool_chunks(
     df: &ParquetDataFrame,
     column: &str,
 ) -> Result<Vec<ffi::ColumnChunk>, String> {
-    let col = df
-        .df
-        .column(column)
-        .map_err(|e| format!("Column '{}' not found: {}", column, e))?;
+    let series = resolve_series(&df.df, column)?;
 
-    let ca = col
+    let ca = series
         .bool()
         .map_err(|e| format!("Column '{}' is not Boolean: {}", column, e))?;
 
-    // Boolean arrays in Arrow use bit-packed storage, not direct bool*
-    // We return the raw bitmap pointer - C++ needs to decode bits
+    // Boolean arrays in Arrow use bit-packed storage, not direct bool*.
+    // We return the raw bitmap pointer plus its bit offset - a sliced array
+    // (e.g. a streamed batch remainder) can start mid-byte, and `bit_offset`
+    // is how C++ finds bit 0 without us realigning the buffer.
     let chunks: Vec<ffi::ColumnChunk> = ca
         .downcast_iter()
         .map(|arr| {
             let values = arr.values();
-            // values.as_slice() returns (&[u8], offset, len)
-            let (slice, _offset, _bit_len) = values.as_slice();
+            let (slice, offset, _bit_len) = values.as_slice();
             ffi::ColumnChunk {
                 ptr: slice.as_ptr() as usize,
                 len: arr.len(), // number of logical boolean elements
+                bit_offset: offset as u8,
             }
         })
         .collect();
@@ -376,13 +629,11 @@ fn parquet_df_get_datetime_chunks(
     df: &ParquetDataFrame,
     column: &str,
 ) -> Result<Vec<ffi::ColumnChunk>, String> {
-    let col = df
-        .df
-        .column(column)
-        .map_err(|e| format!("Column '{}' not found: {}", column, e))?;
+    let series = resolve_series(&df.df, column)?;
 
-    // Datetime is stored as Int64 milliseconds
-    let ca = col
+    // Datetime is always physically Int64, but the unit (ms/us/ns) and
+    // timezone vary per column - see column_info_for / ColumnInfo.
+    let ca = series
         .datetime()
         .map_err(|e| format!("Column '{}' is not Datetime: {}", column, e))?;
 
@@ -393,6 +644,35 @@ fn parquet_df_get_datetime_chunks(
             ffi::ColumnChunk {
                 ptr: values.as_ptr() as usize,
                 len: values.len(),
+                bit_offset: 0,
+            }
+        })
+        .collect();
+
+    Ok(chunks)
+}
+
+fn parquet_df_get_decimal_chunks(
+    df: &ParquetDataFrame,
+    column: &str,
+) -> Result<Vec<ffi::ColumnChunk>, String> {
+    let series = resolve_series(&df.df, column)?;
+
+    let ca = series
+        .decimal()
+        .map_err(|e| format!("Column '{}' is not Decimal: {}", column, e))?;
+
+    // The physical backing array is Int128; scale is shared across the
+    // whole column and reported separately via ColumnInfo::decimal_scale.
+    let chunks: Vec<ffi::ColumnChunk> = ca
+        .physical()
+        .downcast_iter()
+        .map(|arr| {
+            let values = arr.values();
+            ffi::ColumnChunk {
+                ptr: values.as_ptr() as usize,
+                len: values.len(),
+                bit_offset: 0,
             }
         })
         .collect();
@@ -404,12 +684,9 @@ fn parquet_df_get_string_column(
     df: &ParquetDataFrame,
     column: &str,
 ) -> Result<Vec<String>, String> {
-    let col = df
-        .df
-        .column(column)
-        .map_err(|e| format!("Column '{}' not found: {}", column, e))?;
+    let series = resolve_series(&df.df, column)?;
 
-    let ca = col
+    let ca = series
         .str()
         .map_err(|e| format!("Column '{}' is not String: {}", column, e))?;
 
@@ -417,6 +694,309 @@ fn parquet_df_get_string_column(
     Ok(ca.iter().map(|opt| opt.unwrap_or("").to_string()).collect())
 }
 
+fn parquet_df_is_dictionary(df: &ParquetDataFrame, column: &str) -> Result<bool, String> {
+    let series = resolve_series(&df.df, column)?;
+    Ok(matches!(series.dtype(), DataType::Categorical(_, _)))
+}
+
+fn parquet_df_get_dictionary_column(
+    df: &ParquetDataFrame,
+    column: &str,
+) -> Result<ffi::DictionaryColumn, String> {
+    let series = resolve_series(&df.df, column)?;
+
+    let ca = series
+        .categorical()
+        .map_err(|e| format!("Column '{}' is not dictionary-encoded: {}", column, e))?;
+
+    let values: Vec<String> = ca
+        .get_rev_map()
+        .get_categories()
+        .iter()
+        .map(|opt| opt.unwrap_or("").to_string())
+        .collect();
+
+    // The physical codes are a zero-copy u32 chunked array, same chunk
+    // layout as the underlying category array.
+    let codes: Vec<ffi::ColumnChunk> = ca
+        .physical()
+        .downcast_iter()
+        .map(|arr| {
+            let values = arr.values();
+            ffi::ColumnChunk {
+                ptr: values.as_ptr() as usize,
+                len: values.len(),
+                bit_offset: 0,
+            }
+        })
+        .collect();
+
+    Ok(ffi::DictionaryColumn { values, codes })
+}
+
+fn parquet_df_get_validity(
+    df: &ParquetDataFrame,
+    column: &str,
+) -> Result<Vec<ffi::ColumnChunk>, String> {
+    let series = resolve_series(&df.df, column)?;
+
+    // A bit offset (see `ColumnChunk::bit_offset`) is carried through rather
+    // than discarded: a sliced array's validity bitmap can start mid-byte,
+    // and dropping the offset here silently corrupts every bit read on the
+    // C++ side for the second and later batches of a streamed scan.
+    fn to_chunks<'a>(arrays: impl Iterator<Item = &'a dyn Array>) -> Vec<ffi::ColumnChunk> {
+        arrays
+            .map(|arr| match arr.validity() {
+                Some(bitmap) => {
+                    let (slice, offset, _bit_len) = bitmap.as_slice();
+                    ffi::ColumnChunk {
+                        ptr: slice.as_ptr() as usize,
+                        len: arr.len(),
+                        bit_offset: offset as u8,
+                    }
+                }
+                None => ffi::ColumnChunk {
+                    ptr: 0,
+                    len: arr.len(),
+                    bit_offset: 0,
+                },
+            })
+            .collect()
+    }
+
+    match series.dtype() {
+        DataType::Int64 => Ok(to_chunks(
+            series.i64().map_err(|e| e.to_string())?.downcast_iter().map(|a| a as &dyn Array),
+        )),
+        DataType::Int32 => Ok(to_chunks(
+            series.i32().map_err(|e| e.to_string())?.downcast_iter().map(|a| a as &dyn Array),
+        )),
+        DataType::UInt64 => Ok(to_chunks(
+            series.u64().map_err(|e| e.to_string())?.downcast_iter().map(|a| a as &dyn Array),
+        )),
+        DataType::Float64 => Ok(to_chunks(
+            series.f64().map_err(|e| e.to_string())?.downcast_iter().map(|a| a as &dyn Array),
+        )),
+        DataType::Float32 => Ok(to_chunks(
+            series.f32().map_err(|e| e.to_string())?.downcast_iter().map(|a| a as &dyn Array),
+        )),
+        DataType::Boolean => Ok(to_chunks(
+            series.bool().map_err(|e| e.to_string())?.downcast_iter().map(|a| a as &dyn Array),
+        )),
+        DataType::String => Ok(to_chunks(
+            series.str().map_err(|e| e.to_string())?.downcast_iter().map(|a| a as &dyn Array),
+        )),
+        DataType::Datetime(_, _) => Ok(to_chunks(
+            series
+                .datetime()
+                .map_err(|e| e.to_string())?
+                .downcast_iter()
+                .map(|a| a as &dyn Array),
+        )),
+        other => Err(format!(
+            "Column '{}' has unsupported dtype for validity access: {:?}",
+            column, other
+        )),
+    }
+}
+
+/// Extract a `List<T>` column zero-copy: the list is rechunked to a single
+/// chunk first so `offsets` can index straight into one contiguous child
+/// buffer (large-list layout, i.e. `i64` offsets).
+fn list_column_for<T: NativeType>(
+    series: &Series,
+    column: &str,
+) -> Result<ffi::ListColumn, String> {
+    let list_ca = series
+        .list()
+        .map_err(|e| format!("Column '{}' is not a List: {}", column, e))?
+        .clone()
+        .rechunk();
+
+    let arr = list_ca
+        .downcast_iter()
+        .next()
+        .ok_or_else(|| format!("Column '{}' has no data", column))?;
+
+    let offsets: Vec<i64> = arr.offsets().iter().copied().collect();
+
+    let prim = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .ok_or_else(|| format!("Column '{}' child values are not of the requested type", column))?;
+
+    let values = vec![ffi::ColumnChunk {
+        ptr: prim.values().as_ptr() as usize,
+        len: prim.values().len(),
+        bit_offset: 0,
+    }];
+
+    Ok(ffi::ListColumn { values, offsets })
+}
+
+fn parquet_df_get_list_f64(df: &ParquetDataFrame, column: &str) -> Result<ffi::ListColumn, String> {
+    let series = resolve_series(&df.df, column)?;
+    list_column_for::<f64>(&series, column)
+}
+
+fn parquet_df_get_list_i64(df: &ParquetDataFrame, column: &str) -> Result<ffi::ListColumn, String> {
+    let series = resolve_series(&df.df, column)?;
+    list_column_for::<i64>(&series, column)
+}
+
+fn parquet_df_get_list_i32(df: &ParquetDataFrame, column: &str) -> Result<ffi::ListColumn, String> {
+    let series = resolve_series(&df.df, column)?;
+    list_column_for::<i32>(&series, column)
+}
+
+/// List the fields of a Struct column so C++ can discover, then address,
+/// each field via a dotted `parent.child` path on the primitive/list
+/// getters.
+fn parquet_df_struct_fields(
+    df: &ParquetDataFrame,
+    column: &str,
+) -> Result<Vec<ffi::ColumnInfo>, String> {
+    let series = resolve_series(&df.df, column)?;
+    let struct_ca = series
+        .struct_()
+        .map_err(|e| format!("Column '{}' is not a Struct: {}", column, e))?;
+
+    Ok(struct_ca
+        .fields_as_series()
+        .iter()
+        .map(|field| column_info_for(field.name(), field.dtype()))
+        .collect())
+}
+
+// ==================== Streaming Implementation ====================
+
+/// Bounded-memory row-group/batch cursor. Row groups are pulled forward-only
+/// from a persistent `BatchedParquetReader` (the same mechanism
+/// `BatchedParquetWriter` uses on the write side), so a full scan costs O(n)
+/// instead of re-reading the file from the start on every `parquet_stream_next`
+/// call. Column projection is pushed into the reader at `parquet_stream_open`
+/// time; filters are re-applied to each freshly read row group since the
+/// batched reader itself has no predicate pushdown.
+pub struct ParquetBatchStream {
+    path: String,
+    filters: Vec<Expr>,
+    batch_rows: usize,
+    reader: polars::io::parquet::read::BatchedParquetReader,
+    pending: Option<DataFrame>,
+    exhausted: bool,
+}
+
+fn stream_filter_to_expr(f: &ffi::StreamFilter) -> Expr {
+    if let Ok(v) = f.value.parse::<i64>() {
+        make_filter_expr(&f.column, f.op, lit(v))
+    } else if let Ok(v) = f.value.parse::<f64>() {
+        make_filter_expr(&f.column, f.op, lit(v))
+    } else if let Ok(v) = f.value.parse::<bool>() {
+        make_filter_expr(&f.column, f.op, lit(v))
+    } else {
+        make_filter_expr(&f.column, f.op, lit(f.value.clone()))
+    }
+}
+
+fn parquet_stream_open(
+    path: &str,
+    batch_rows: usize,
+    columns: Vec<String>,
+    filters: Vec<ffi::StreamFilter>,
+) -> Result<Box<ParquetBatchStream>, String> {
+    if !std::path::Path::new(path).exists() {
+        return Err(format!("File not found: {}", path));
+    }
+    if batch_rows == 0 {
+        return Err("batch_rows must be greater than zero".to_string());
+    }
+
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut pq_reader = polars::io::parquet::read::ParquetReader::new(file);
+    if !columns.is_empty() {
+        pq_reader = pq_reader.with_columns(Some(columns));
+    }
+    let reader = pq_reader
+        .batched(batch_rows)
+        .map_err(|e| e.to_string())?;
+
+    let filters = filters.iter().map(stream_filter_to_expr).collect();
+
+    Ok(Box::new(ParquetBatchStream {
+        path: path.to_string(),
+        filters,
+        batch_rows,
+        reader,
+        pending: None,
+        exhausted: false,
+    }))
+}
+
+fn parquet_stream_schema(stream: &ParquetBatchStream) -> Result<Vec<ffi::ColumnInfo>, String> {
+    let args = ScanArgsParquet::default();
+    let lf = LazyFrame::scan_parquet(&stream.path, args).map_err(|e| e.to_string())?;
+    let schema = lf.schema().map_err(|e| e.to_string())?;
+
+    Ok(schema
+        .iter()
+        .map(|(name, dtype)| column_info_for(name, dtype))
+        .collect())
+}
+
+/// Pull row groups from `stream.reader` into `stream.pending` until it holds
+/// at least `stream.batch_rows` rows or the reader is exhausted.
+fn fill_pending(stream: &mut ParquetBatchStream) -> Result<(), String> {
+    while !stream.exhausted
+        && stream.pending.as_ref().map_or(0, |df| df.height()) < stream.batch_rows
+    {
+        match stream.reader.next_batches(1).map_err(|e| e.to_string())? {
+            Some(batches) if !batches.is_empty() => {
+                let mut iter = batches.into_iter();
+                let mut group = iter.next().expect("checked non-empty above");
+                for batch in iter {
+                    group.vstack_mut(&batch).map_err(|e| e.to_string())?;
+                }
+                for filter_expr in &stream.filters {
+                    group = group
+                        .lazy()
+                        .filter(filter_expr.clone())
+                        .collect()
+                        .map_err(|e| e.to_string())?;
+                }
+                stream.pending = Some(match stream.pending.take() {
+                    Some(mut existing) => {
+                        existing.vstack_mut(&group).map_err(|e| e.to_string())?;
+                        existing
+                    }
+                    None => group,
+                });
+            }
+            _ => stream.exhausted = true,
+        }
+    }
+    Ok(())
+}
+
+fn parquet_stream_next(
+    stream: &mut ParquetBatchStream,
+) -> Result<Option<Box<ParquetDataFrame>>, String> {
+    fill_pending(stream)?;
+
+    let available = stream.pending.as_ref().map_or(0, |df| df.height());
+    if available == 0 {
+        return Ok(None);
+    }
+
+    let pending = stream.pending.take().expect("available > 0 implies Some");
+    let take = stream.batch_rows.min(available);
+    let out = pending.slice(0, take);
+    let rest = pending.slice(take as i64, available - take);
+    stream.pending = if rest.height() > 0 { Some(rest) } else { None };
+
+    Ok(Some(Box::new(ParquetDataFrame { df: out })))
+}
+
 // ==================== Legacy Implementation ====================
 
 /// Wrapper around a Polars DataFrame loaded from a Parquet file.
@@ -462,10 +1042,7 @@ fn parquet_reader_columns(reader: &ParquetReader) -> Vec<ffi::ColumnInfo> {
         .df
         .get_columns()
         .iter()
-        .map(|col| ffi::ColumnInfo {
-            name: col.name().to_string(),
-            dtype: dtype_to_column_type(col.dtype()),
-        })
+        .map(|col| column_info_for(col.name(), col.dtype()))
         .collect()
 }
 
@@ -633,6 +1210,8 @@ pub struct ParquetQuery {
     path: String,
     columns: Vec<String>,
     filters: Vec<Expr>,
+    order_by: Option<(String, bool)>,
+    limit: Option<usize>,
 }
 
 fn make_filter_expr(column: &str, op: ffi::FilterOp, value: Expr) -> Expr {
@@ -662,6 +1241,8 @@ fn parquet_query_new(path: &str) -> Result<Box<ParquetQuery>, String> {
         path: path.to_string(),
         columns: Vec::new(),
         filters: Vec::new(),
+        order_by: None,
+        limit: None,
     }))
 }
 
@@ -705,6 +1286,218 @@ fn parquet_query_filter_bool(
     query.filters.push(make_filter_expr(column, op, lit(value)));
 }
 
+fn parquet_query_order_by(query: &mut ParquetQuery, column: &str, descending: bool) {
+    query.order_by = Some((column.to_string(), descending));
+}
+
+fn parquet_query_limit(query: &mut ParquetQuery, k: usize) {
+    query.limit = Some(k);
+}
+
+/// Restrict `lf` to the union of the given row-index ranges via repeated
+/// `slice` + `concat`, preserving row order. Mirrors the helper of the same
+/// name in `basis::parquet`'s page-index pruning.
+fn union_row_ranges(lf: LazyFrame, ranges: &[std::ops::Range<usize>]) -> LazyFrame {
+    if ranges.is_empty() {
+        return lf.limit(0);
+    }
+
+    let slices: Vec<LazyFrame> = ranges
+        .iter()
+        .map(|r| lf.clone().slice(r.start as i64, (r.end - r.start) as u32))
+        .collect();
+
+    concat(slices, UnionArgs::default()).unwrap_or(lf)
+}
+
+/// A row group's min/max statistics in their native comparison type. Int32
+/// widens losslessly to `i64`, but Int64 stays `i64` rather than going
+/// through `f64` - an `i64` magnitude above 2^53 loses precision as `f64`,
+/// which can flip a pruning comparison and drop a row group that actually
+/// holds a top-k row.
+#[derive(Clone, Copy)]
+enum MinMax {
+    I64(i64, i64),
+    F64(f64, f64),
+}
+
+fn parquet_min_max_native(stats: &dyn parquet2::statistics::Statistics) -> Option<MinMax> {
+    use parquet2::schema::types::PhysicalType;
+    use parquet2::statistics::PrimitiveStatistics;
+
+    match stats.physical_type() {
+        PhysicalType::Int32 => {
+            let s = stats.as_any().downcast_ref::<PrimitiveStatistics<i32>>()?;
+            Some(MinMax::I64(s.min_value? as i64, s.max_value? as i64))
+        }
+        PhysicalType::Int64 => {
+            let s = stats.as_any().downcast_ref::<PrimitiveStatistics<i64>>()?;
+            Some(MinMax::I64(s.min_value?, s.max_value?))
+        }
+        PhysicalType::Float => {
+            let s = stats.as_any().downcast_ref::<PrimitiveStatistics<f32>>()?;
+            Some(MinMax::F64(s.min_value? as f64, s.max_value? as f64))
+        }
+        PhysicalType::Double => {
+            let s = stats.as_any().downcast_ref::<PrimitiveStatistics<f64>>()?;
+            Some(MinMax::F64(s.min_value?, s.max_value?))
+        }
+        _ => None,
+    }
+}
+
+/// Row groups likely to contain a top-`k` `ORDER BY column [DESC]` result.
+///
+/// Maintains a bounded (size-`k`) heap of the best *guaranteed* values
+/// among already-accepted row groups, whose root is the worst of them (the
+/// smallest for descending order, the largest for ascending). The most promising groups (by their
+/// best *possible* value - max for descending order, min for ascending) are
+/// evaluated first; once the heap holds `k` guarantees, any further group
+/// whose best-possible value cannot beat the heap's root is provably
+/// outside the top-k and is skipped. Row groups without decodable min/max
+/// statistics are conservatively kept in full.
+fn topk_surviving_ranges(
+    path: &str,
+    column: &str,
+    descending: bool,
+    k: usize,
+) -> Result<Vec<std::ops::Range<usize>>, String> {
+    use parquet2::read::read_metadata;
+    use std::cmp::Ordering;
+    use std::collections::BinaryHeap;
+
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let metadata = read_metadata(&mut file).map_err(|e| e.to_string())?;
+
+    let col_idx = metadata
+        .schema()
+        .fields()
+        .iter()
+        .position(|f| f.name() == column);
+
+    // Compares in the statistics' native type so an i64 magnitude above 2^53
+    // (where `f64` starts losing integer precision) still orders correctly.
+    #[derive(Clone, Copy, PartialEq)]
+    enum Bound {
+        I64(i64),
+        F64(f64),
+    }
+    impl Bound {
+        fn as_f64(&self) -> f64 {
+            match self {
+                Bound::I64(v) => *v as f64,
+                Bound::F64(v) => *v,
+            }
+        }
+    }
+    impl Eq for Bound {}
+    impl PartialOrd for Bound {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            match (self, other) {
+                (Bound::I64(a), Bound::I64(b)) => a.partial_cmp(b),
+                (Bound::F64(a), Bound::F64(b)) => a.partial_cmp(b),
+                // A column's statistics are always one physical type, so
+                // this only arises if the schema itself mixes types across
+                // row groups; fall back to a lossy but harmless comparison.
+                _ => self.as_f64().partial_cmp(&other.as_f64()),
+            }
+        }
+    }
+    impl Ord for Bound {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        }
+    }
+
+    struct Candidate {
+        range: std::ops::Range<usize>,
+        best: Bound,
+        guaranteed: Bound,
+    }
+
+    let mut offset = 0usize;
+    let mut candidates = Vec::new();
+    let mut kept_in_full = Vec::new();
+
+    for row_group in metadata.row_groups.iter() {
+        let n_rows = row_group.num_rows();
+        let range = offset..offset + n_rows;
+        offset += n_rows;
+
+        let stats = col_idx
+            .and_then(|idx| row_group.columns().get(idx))
+            .and_then(|chunk| chunk.statistics())
+            .and_then(|s| s.ok())
+            .and_then(|s| parquet_min_max_native(s.as_ref()));
+
+        match stats {
+            Some(min_max) => {
+                let (min, max) = match min_max {
+                    MinMax::I64(min, max) => (Bound::I64(min), Bound::I64(max)),
+                    MinMax::F64(min, max) => (Bound::F64(min), Bound::F64(max)),
+                };
+                let (best, guaranteed) = if descending { (max, min) } else { (min, max) };
+                candidates.push(Candidate { range, best, guaranteed });
+            }
+            // No usable statistics for this row group; can't safely skip it.
+            None => kept_in_full.push(range),
+        }
+    }
+
+    candidates.sort_by(|a, b| {
+        if descending {
+            b.best.partial_cmp(&a.best).unwrap_or(Ordering::Equal)
+        } else {
+            a.best.partial_cmp(&b.best).unwrap_or(Ordering::Equal)
+        }
+    });
+
+    // Heap entry ordered so the root is the worst kept guarantee: the
+    // smallest when descending, the largest when ascending.
+    #[derive(PartialEq, Eq)]
+    struct Kept(Bound, bool);
+    impl PartialOrd for Kept {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Kept {
+        fn cmp(&self, other: &Self) -> Ordering {
+            if self.1 { other.0.cmp(&self.0) } else { self.0.cmp(&other.0) }
+        }
+    }
+
+    let mut heap: BinaryHeap<Kept> = BinaryHeap::new();
+    let mut surviving = kept_in_full;
+
+    for c in candidates {
+        let boundary = heap.peek().map(|kept| kept.0);
+        let prunable = heap.len() >= k
+            && match boundary {
+                Some(t) if descending => c.best <= t,
+                Some(t) => c.best >= t,
+                None => false,
+            };
+
+        if prunable {
+            continue;
+        }
+
+        surviving.push(c.range);
+        heap.push(Kept(c.guaranteed, descending));
+        while heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    surviving.sort_by_key(|r| r.start);
+    Ok(surviving)
+}
+
 fn execute_query(query: &ParquetQuery) -> Result<DataFrame, String> {
     let args = ScanArgsParquet::default();
     let mut lf = LazyFrame::scan_parquet(&query.path, args).map_err(|e| e.to_string())?;
@@ -720,6 +1513,36 @@ fn execute_query(query: &ParquetQuery) -> Result<DataFrame, String> {
         lf = lf.filter(filter_expr.clone());
     }
 
+    match (&query.order_by, query.limit) {
+        (Some((sort_col, descending)), Some(k)) => {
+            // True top-k: prune row groups via min/max statistics before
+            // sorting the (hopefully much smaller) candidate set. The pruning
+            // works on file row positions and whole-row-group statistics, so
+            // it only applies when no filter has removed rows.
+            if query.filters.is_empty() {
+                if let Ok(ranges) = topk_surviving_ranges(&query.path, sort_col, *descending, k) {
+                    lf = union_row_ranges(lf, &ranges);
+                }
+            }
+            lf = lf
+                .sort(
+                    [sort_col.as_str()],
+                    SortMultipleOptions::default().with_order_descending(*descending),
+                )
+                .limit(k as u32);
+        }
+        (Some((sort_col, descending)), None) => {
+            lf = lf.sort(
+                [sort_col.as_str()],
+                SortMultipleOptions::default().with_order_descending(*descending),
+            );
+        }
+        (None, Some(k)) => {
+            lf = lf.limit(k as u32);
+        }
+        (None, None) => {}
+    }
+
     lf.collect().map_err(|e| e.to_string())
 }
 
@@ -733,6 +1556,160 @@ fn parquet_query_collect_df(query: Box<ParquetQuery>) -> Result<Box<ParquetDataF
     Ok(Box::new(ParquetDataFrame { df }))
 }
 
+// ==================== Metadata Inspection Implementation ====================
+
+/// Parsed Parquet footer - row-group/column-chunk layout and statistics,
+/// without decoding any data pages.
+pub struct ParquetMetadata {
+    metadata: parquet2::metadata::FileMetaData,
+}
+
+fn parquet_metadata_open(path: &str) -> Result<Box<ParquetMetadata>, String> {
+    use parquet2::read::read_metadata;
+
+    let mut file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let metadata = read_metadata(&mut file).map_err(|e| e.to_string())?;
+    Ok(Box::new(ParquetMetadata { metadata }))
+}
+
+fn parquet_metadata_num_row_groups(meta: &ParquetMetadata) -> usize {
+    meta.metadata.row_groups.len()
+}
+
+fn parquet_metadata_row_group_rows(meta: &ParquetMetadata, rg: usize) -> Result<usize, String> {
+    meta.metadata
+        .row_groups
+        .get(rg)
+        .map(|g| g.num_rows())
+        .ok_or_else(|| format!("Row group {} out of range", rg))
+}
+
+fn parquet_metadata_row_group_bytes(meta: &ParquetMetadata, rg: usize) -> Result<usize, String> {
+    meta.metadata
+        .row_groups
+        .get(rg)
+        .map(|g| g.total_byte_size() as usize)
+        .ok_or_else(|| format!("Row group {} out of range", rg))
+}
+
+/// Decode a column chunk's statistics into the typed `ColumnStats` shape,
+/// picking the physical-type branch that matches what the writer recorded.
+fn column_stats_from(stats: &dyn parquet2::statistics::Statistics) -> ffi::ColumnStats {
+    use parquet2::schema::types::PhysicalType;
+    use parquet2::statistics::{BinaryStatistics, PrimitiveStatistics};
+
+    let mut out = ffi::ColumnStats {
+        has_stats: true,
+        null_count: stats.null_count().unwrap_or(-1),
+        has_min_max_i64: false,
+        min_i64: 0,
+        max_i64: 0,
+        has_min_max_f64: false,
+        min_f64: 0.0,
+        max_f64: 0.0,
+        has_min_max_str: false,
+        min_str: String::new(),
+        max_str: String::new(),
+    };
+
+    match stats.physical_type() {
+        PhysicalType::Int32 => {
+            if let Some(s) = stats.as_any().downcast_ref::<PrimitiveStatistics<i32>>() {
+                if let (Some(min), Some(max)) = (s.min_value, s.max_value) {
+                    out.has_min_max_i64 = true;
+                    out.min_i64 = min as i64;
+                    out.max_i64 = max as i64;
+                }
+            }
+        }
+        PhysicalType::Int64 => {
+            if let Some(s) = stats.as_any().downcast_ref::<PrimitiveStatistics<i64>>() {
+                if let (Some(min), Some(max)) = (s.min_value, s.max_value) {
+                    out.has_min_max_i64 = true;
+                    out.min_i64 = min;
+                    out.max_i64 = max;
+                }
+            }
+        }
+        PhysicalType::Float => {
+            if let Some(s) = stats.as_any().downcast_ref::<PrimitiveStatistics<f32>>() {
+                if let (Some(min), Some(max)) = (s.min_value, s.max_value) {
+                    out.has_min_max_f64 = true;
+                    out.min_f64 = min as f64;
+                    out.max_f64 = max as f64;
+                }
+            }
+        }
+        PhysicalType::Double => {
+            if let Some(s) = stats.as_any().downcast_ref::<PrimitiveStatistics<f64>>() {
+                if let (Some(min), Some(max)) = (s.min_value, s.max_value) {
+                    out.has_min_max_f64 = true;
+                    out.min_f64 = min;
+                    out.max_f64 = max;
+                }
+            }
+        }
+        PhysicalType::ByteArray => {
+            if let Some(s) = stats.as_any().downcast_ref::<BinaryStatistics>() {
+                if let Some(min) = &s.min_value {
+                    out.has_min_max_str = true;
+                    out.min_str = String::from_utf8_lossy(min).into_owned();
+                }
+                if let Some(max) = &s.max_value {
+                    out.has_min_max_str = true;
+                    out.max_str = String::from_utf8_lossy(max).into_owned();
+                }
+            }
+        }
+        _ => {}
+    }
+
+    out
+}
+
+fn parquet_metadata_column_stats(
+    meta: &ParquetMetadata,
+    rg: usize,
+    column: &str,
+) -> Result<ffi::ColumnStats, String> {
+    let row_group = meta
+        .metadata
+        .row_groups
+        .get(rg)
+        .ok_or_else(|| format!("Row group {} out of range", rg))?;
+
+    let col_idx = meta
+        .metadata
+        .schema()
+        .fields()
+        .iter()
+        .position(|f| f.name() == column)
+        .ok_or_else(|| format!("Column '{}' not found", column))?;
+
+    let chunk = row_group
+        .columns()
+        .get(col_idx)
+        .ok_or_else(|| format!("Column '{}' not found in row group {}", column, rg))?;
+
+    match chunk.statistics() {
+        Some(Ok(stats)) => Ok(column_stats_from(stats.as_ref())),
+        Some(Err(e)) => Err(e.to_string()),
+        None => Ok(ffi::ColumnStats {
+            has_stats: false,
+            null_count: -1,
+            has_min_max_i64: false,
+            min_i64: 0,
+            max_i64: 0,
+            has_min_max_f64: false,
+            min_f64: 0.0,
+            max_f64: 0.0,
+            has_min_max_str: false,
+            min_str: String::new(),
+            max_str: String::new(),
+        }),
+    }
+}
+
 fn parquet_writer_finish(writer: Box<ParquetWriter>) -> Result<(), String> {
     // Build DataFrame from columns in order
     let columns: Vec<Column> = writer
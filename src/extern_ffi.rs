@@ -0,0 +1,12 @@
+//! Reverse FFI: bindings into an external C market-data/pricing library.
+//!
+//! The bindings themselves are generated at build time by `build.rs` from
+//! the header at `BASIS_RS_EXTERN_HEADER` (see that file for the allowlist
+//! and codegen details) and written to `OUT_DIR/extern_bindings.rs`. This
+//! module only wraps them in their own namespace and re-exports them as raw,
+//! unsafe `extern "C"` items - callers are expected to build a safe wrapper
+//! on top, the same way `crate::ffi` wraps our own exported C ABI.
+
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals, dead_code)]
+
+include!(concat!(env!("OUT_DIR"), "/extern_bindings.rs"));
@@ -4,6 +4,7 @@
 
 use crate::basis::parquet::{ParquetReader, ParquetWriter};
 use libc::{c_char, c_int, size_t};
+use polars::export::arrow::ffi as arrow_ffi;
 use polars::prelude::*;
 use std::ffi::{CStr, CString};
 use std::ptr;
@@ -22,43 +23,189 @@ pub struct BasisDataFrame {
     inner: DataFrame,
 }
 
-/// Result of reading an Int64 column.
+/// Result of reading an Int64 column. Null values are reported as `0`; use
+/// `basis_df_get_int64_column_nullable` if you need to distinguish a real
+/// `0` from a null.
 #[repr(C)]
 pub struct Int64Column {
     pub data: *mut i64,
     pub len: size_t,
 }
 
-/// Result of reading a Float64 column.
+/// Result of reading a Float64 column. Null values are reported as `0.0`;
+/// use `basis_df_get_float64_column_nullable` if you need to distinguish a
+/// real `0.0` from a null.
 #[repr(C)]
 pub struct Float64Column {
     pub data: *mut f64,
     pub len: size_t,
 }
 
-/// Result of reading a Bool column.
+/// Result of reading a Bool column. Null values are reported as `false`;
+/// use `basis_df_get_bool_column_nullable` if you need to distinguish a
+/// real `false` from a null.
 #[repr(C)]
 pub struct BoolColumn {
     pub data: *mut bool,
     pub len: size_t,
 }
 
-/// Result of reading a String column.
+/// Result of reading a String column. Null values are reported as an empty
+/// string; use `basis_df_get_string_column_nullable` if you need to
+/// distinguish a real empty string from a null.
 #[repr(C)]
 pub struct StringColumn {
     pub data: *mut *mut c_char,
     pub len: size_t,
 }
 
+/// Result of reading an Int64 column with explicit null tracking.
+#[repr(C)]
+pub struct Int64ColumnNullable {
+    pub data: *mut i64,
+    pub len: size_t,
+    /// Packed validity bitmap, one bit per row (1 = valid), or NULL if the
+    /// column has no nulls. Bit `i` lives at `validity[i / 8] & (1 << (i % 8))`.
+    pub validity: *mut u8,
+    /// Number of null rows. Always 0 when `validity` is NULL.
+    pub null_count: size_t,
+}
+
+/// Result of reading a Float64 column with explicit null tracking.
+#[repr(C)]
+pub struct Float64ColumnNullable {
+    pub data: *mut f64,
+    pub len: size_t,
+    /// Packed validity bitmap, one bit per row (1 = valid), or NULL if the
+    /// column has no nulls. Bit `i` lives at `validity[i / 8] & (1 << (i % 8))`.
+    pub validity: *mut u8,
+    /// Number of null rows. Always 0 when `validity` is NULL.
+    pub null_count: size_t,
+}
+
+/// Result of reading a Bool column with explicit null tracking.
+#[repr(C)]
+pub struct BoolColumnNullable {
+    pub data: *mut bool,
+    pub len: size_t,
+    /// Packed validity bitmap, one bit per row (1 = valid), or NULL if the
+    /// column has no nulls. Bit `i` lives at `validity[i / 8] & (1 << (i % 8))`.
+    pub validity: *mut u8,
+    /// Number of null rows. Always 0 when `validity` is NULL.
+    pub null_count: size_t,
+}
+
+/// Result of reading a String column with explicit null tracking.
+#[repr(C)]
+pub struct StringColumnNullable {
+    pub data: *mut *mut c_char,
+    pub len: size_t,
+    /// Packed validity bitmap, one bit per row (1 = valid), or NULL if the
+    /// column has no nulls. A null row's `data[i]` is an empty string for
+    /// backward compatibility; check the bitmap to distinguish it from a
+    /// genuine empty string.
+    pub validity: *mut u8,
+    /// Number of null rows. Always 0 when `validity` is NULL.
+    pub null_count: size_t,
+}
+
+/// Build a packed validity bitmap (1 = valid) from an iterator of `Option`s.
+/// Returns `(ptr, null_count, values)`; `ptr` is NULL when there are no nulls
+/// at all, and `values` holds the collected items for building the data buffer.
+fn build_validity_bitmap<'a, T: 'a>(
+    iter: impl Iterator<Item = Option<T>> + 'a,
+    len: usize,
+) -> (*mut u8, size_t, Vec<Option<T>>) {
+    let values: Vec<Option<T>> = iter.collect();
+    let null_count = values.iter().filter(|v| v.is_none()).count();
+
+    if null_count == 0 {
+        return (ptr::null_mut(), 0, values);
+    }
+
+    let n_bytes = len.div_ceil(8);
+    let mut bitmap = vec![0u8; n_bytes];
+    for (i, v) in values.iter().enumerate() {
+        if v.is_some() {
+            bitmap[i / 8] |= 1 << (i % 8);
+        }
+    }
+
+    let mut boxed = bitmap.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+    (ptr, null_count, values)
+}
+
+/// Free a validity bitmap previously allocated by `build_validity_bitmap`.
+unsafe fn free_validity_bitmap(validity: *mut u8, len: size_t) {
+    if !validity.is_null() {
+        let n_bytes = len.div_ceil(8);
+        drop(Vec::from_raw_parts(validity, n_bytes, n_bytes));
+    }
+}
+
 use std::cell::RefCell;
 
+/// Machine-readable category for a `BasisError`, mirroring the `BASIS_ERR_*`
+/// codes but extensible with per-kind context.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasisErrorKind {
+    Io = 0,
+    SchemaMismatch = 1,
+    ColumnNotFound = 2,
+    InvalidUtf8 = 3,
+    PolarsError = 4,
+    NullPointer = 5,
+    TypeMismatch = 6,
+    Other = 7,
+}
+
+/// A record of the most recent error, carrying structure (kind, column,
+/// expected/actual dtype) beyond a flat message string.
+#[derive(Clone)]
+struct ErrorRecord {
+    kind: BasisErrorKind,
+    message: String,
+    column: Option<String>,
+    expected: Option<String>,
+    actual: Option<String>,
+}
+
+/// Opaque handle to a richer, structured error. Obtained via
+/// `basis_get_last_error_detailed` and freed with `basis_error_free`.
+pub struct BasisError {
+    kind: BasisErrorKind,
+    message: CString,
+    column: Option<CString>,
+    expected: Option<CString>,
+    actual: Option<CString>,
+}
+
 thread_local! {
-    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+    static LAST_ERROR: RefCell<Option<ErrorRecord>> = const { RefCell::new(None) };
 }
 
 fn set_error(msg: &str) {
+    set_error_detailed(BasisErrorKind::Other, msg, None, None, None);
+}
+
+fn set_error_detailed(
+    kind: BasisErrorKind,
+    msg: &str,
+    column: Option<&str>,
+    expected: Option<&str>,
+    actual: Option<&str>,
+) {
     LAST_ERROR.with(|e| {
-        *e.borrow_mut() = CString::new(msg).ok();
+        *e.borrow_mut() = Some(ErrorRecord {
+            kind,
+            message: msg.to_string(),
+            column: column.map(|s| s.to_string()),
+            expected: expected.map(|s| s.to_string()),
+            actual: actual.map(|s| s.to_string()),
+        });
     });
 }
 
@@ -66,11 +213,106 @@ fn set_error(msg: &str) {
 /// The returned string is valid until the next FFI call.
 #[no_mangle]
 pub extern "C" fn basis_get_last_error() -> *const c_char {
+    thread_local! {
+        static LAST_ERROR_CSTR: RefCell<Option<CString>> = const { RefCell::new(None) };
+    }
+    LAST_ERROR.with(|e| {
+        LAST_ERROR_CSTR.with(|cstr| {
+            *cstr.borrow_mut() = e
+                .borrow()
+                .as_ref()
+                .and_then(|r| CString::new(r.message.clone()).ok());
+            cstr.borrow().as_ref().map_or(ptr::null(), |s| s.as_ptr())
+        })
+    })
+}
+
+/// Get the last error as a structured, owned `BasisError` handle, or NULL
+/// if there is no error. Unlike `basis_get_last_error`, the returned handle
+/// is valid until explicitly freed with `basis_error_free`.
+#[no_mangle]
+pub extern "C" fn basis_get_last_error_detailed() -> *mut BasisError {
     LAST_ERROR.with(|e| {
-        e.borrow().as_ref().map_or(ptr::null(), |s| s.as_ptr())
+        e.borrow().as_ref().map_or(ptr::null_mut(), |r| {
+            Box::into_raw(Box::new(BasisError {
+                kind: r.kind,
+                message: CString::new(r.message.clone()).unwrap_or_default(),
+                column: r.column.as_ref().and_then(|c| CString::new(c.clone()).ok()),
+                expected: r
+                    .expected
+                    .as_ref()
+                    .and_then(|c| CString::new(c.clone()).ok()),
+                actual: r.actual.as_ref().and_then(|c| CString::new(c.clone()).ok()),
+            }))
+        })
     })
 }
 
+/// The error's machine-readable category.
+#[no_mangle]
+pub extern "C" fn basis_error_kind(err: *const BasisError) -> BasisErrorKind {
+    if err.is_null() {
+        return BasisErrorKind::Other;
+    }
+    unsafe { (*err).kind }
+}
+
+/// The error's human-readable message. Valid until `basis_error_free`.
+#[no_mangle]
+pub extern "C" fn basis_error_message(err: *const BasisError) -> *const c_char {
+    if err.is_null() {
+        return ptr::null();
+    }
+    unsafe { (*err).message.as_ptr() }
+}
+
+/// The column associated with the error, or NULL if not applicable.
+#[no_mangle]
+pub extern "C" fn basis_error_column(err: *const BasisError) -> *const c_char {
+    if err.is_null() {
+        return ptr::null();
+    }
+    unsafe {
+        (*err)
+            .column
+            .as_ref()
+            .map_or(ptr::null(), |c| c.as_ptr())
+    }
+}
+
+/// The expected dtype for a `SchemaMismatch` error, or NULL if not applicable.
+#[no_mangle]
+pub extern "C" fn basis_error_expected(err: *const BasisError) -> *const c_char {
+    if err.is_null() {
+        return ptr::null();
+    }
+    unsafe {
+        (*err)
+            .expected
+            .as_ref()
+            .map_or(ptr::null(), |c| c.as_ptr())
+    }
+}
+
+/// The actual dtype for a `SchemaMismatch` error, or NULL if not applicable.
+#[no_mangle]
+pub extern "C" fn basis_error_actual(err: *const BasisError) -> *const c_char {
+    if err.is_null() {
+        return ptr::null();
+    }
+    unsafe { (*err).actual.as_ref().map_or(ptr::null(), |c| c.as_ptr()) }
+}
+
+/// Free a `BasisError` handle.
+#[no_mangle]
+pub extern "C" fn basis_error_free(err: *mut BasisError) {
+    if !err.is_null() {
+        unsafe {
+            drop(Box::from_raw(err));
+        }
+    }
+}
+
 /// Clear the last error.
 #[no_mangle]
 pub extern "C" fn basis_clear_error() {
@@ -79,19 +321,73 @@ pub extern "C" fn basis_clear_error() {
     });
 }
 
+/// Populate `out_err`, if non-null, with a fresh `BasisError` built from the
+/// thread-local error state. A no-op when `out_err` is NULL, so callers that
+/// only check the return code / `basis_get_last_error()` are unaffected.
+fn populate_out_err(out_err: *mut *mut BasisError) {
+    if !out_err.is_null() {
+        unsafe {
+            *out_err = basis_get_last_error_detailed();
+        }
+    }
+}
+
+/// Record a `ColumnNotFound` error for `name` and populate `out_err`.
+/// Returns `BASIS_ERR_COLUMN_NOT_FOUND` for convenience at the call site.
+fn fail_column_not_found(name: &str, out_err: *mut *mut BasisError) -> c_int {
+    set_error_detailed(
+        BasisErrorKind::ColumnNotFound,
+        &format!("column '{}' not found", name),
+        Some(name),
+        None,
+        None,
+    );
+    populate_out_err(out_err);
+    BASIS_ERR_COLUMN_NOT_FOUND
+}
+
+/// Record a `SchemaMismatch` error for `name` (requested dtype `expected`,
+/// actual dtype `actual`) and populate `out_err`. Returns
+/// `BASIS_ERR_TYPE_MISMATCH` for convenience at the call site.
+fn fail_type_mismatch(name: &str, expected: &str, actual: &DataType, out_err: *mut *mut BasisError) -> c_int {
+    let actual_str = format!("{:?}", actual);
+    set_error_detailed(
+        BasisErrorKind::SchemaMismatch,
+        &format!("column '{}' is not {}: found {}", name, expected, actual_str),
+        Some(name),
+        Some(expected),
+        Some(&actual_str),
+    );
+    populate_out_err(out_err);
+    BASIS_ERR_TYPE_MISMATCH
+}
+
 /// Read a Parquet file and return a DataFrame handle.
 /// Returns NULL on error. Use `basis_get_last_error()` for details.
 #[no_mangle]
 pub extern "C" fn basis_parquet_read(path: *const c_char) -> *mut BasisDataFrame {
+    basis_parquet_read_ex(path, ptr::null_mut())
+}
+
+/// Same as `basis_parquet_read`, but takes an `out_err` to receive a
+/// structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_parquet_read_ex(
+    path: *const c_char,
+    out_err: *mut *mut BasisError,
+) -> *mut BasisDataFrame {
     if path.is_null() {
         set_error("path is null");
+        populate_out_err(out_err);
         return ptr::null_mut();
     }
 
     let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_error("invalid UTF-8 in path");
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in path", None, None, None);
+            populate_out_err(out_err);
             return ptr::null_mut();
         }
     };
@@ -99,25 +395,41 @@ pub extern "C" fn basis_parquet_read(path: *const c_char) -> *mut BasisDataFrame
     match ParquetReader::new(path_str).read() {
         Ok(df) => Box::into_raw(Box::new(BasisDataFrame { inner: df })),
         Err(e) => {
-            set_error(&e.to_string());
+            set_error_detailed(BasisErrorKind::Io, &e.to_string(), None, None, None);
+            populate_out_err(out_err);
             ptr::null_mut()
         }
     }
 }
 
 /// Write a DataFrame to a Parquet file.
-/// Returns BASIS_OK on success, negative error code on failure.
+/// Returns BASIS_OK on success, negative error code on failure. Use
+/// `basis_get_last_error()` for details.
 #[no_mangle]
 pub extern "C" fn basis_parquet_write(df: *mut BasisDataFrame, path: *const c_char) -> c_int {
+    basis_parquet_write_ex(df, path, ptr::null_mut())
+}
+
+/// Same as `basis_parquet_write`, but takes an `out_err` to receive a
+/// structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_parquet_write_ex(
+    df: *mut BasisDataFrame,
+    path: *const c_char,
+    out_err: *mut *mut BasisError,
+) -> c_int {
     if df.is_null() || path.is_null() {
-        set_error("null pointer");
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
         return BASIS_ERR_NULL_PTR;
     }
 
     let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_error("invalid UTF-8 in path");
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in path", None, None, None);
+            populate_out_err(out_err);
             return BASIS_ERR_INVALID_UTF8;
         }
     };
@@ -127,7 +439,8 @@ pub extern "C" fn basis_parquet_write(df: *mut BasisDataFrame, path: *const c_ch
     match ParquetWriter::new(path_str).write(df_ref) {
         Ok(()) => BASIS_OK,
         Err(e) => {
-            set_error(&e.to_string());
+            set_error_detailed(BasisErrorKind::Io, &e.to_string(), None, None, None);
+            populate_out_err(out_err);
             BASIS_ERR_IO
         }
     }
@@ -175,16 +488,32 @@ pub extern "C" fn basis_df_get_int64_column(
     df: *const BasisDataFrame,
     name: *const c_char,
     out: *mut Int64Column,
+) -> c_int {
+    basis_df_get_int64_column_ex(df, name, out, ptr::null_mut())
+}
+
+/// Get an Int64 column by name. Same as `basis_df_get_int64_column`, but
+/// takes an `out_err` to receive a structured `BasisError` on failure
+/// instead of only updating `basis_get_last_error()`.
+/// The caller must free the returned data with `basis_int64_column_free`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_int64_column_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut Int64Column,
+    out_err: *mut *mut BasisError,
 ) -> c_int {
     if df.is_null() || name.is_null() || out.is_null() {
-        set_error("null pointer");
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
         return BASIS_ERR_NULL_PTR;
     }
 
     let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_error("invalid UTF-8 in column name");
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
             return BASIS_ERR_INVALID_UTF8;
         }
     };
@@ -193,26 +522,16 @@ pub extern "C" fn basis_df_get_int64_column(
 
     let col = match df_ref.column(name_str) {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' not found", name_str));
-            return BASIS_ERR_COLUMN_NOT_FOUND;
-        }
+        Err(_) => return fail_column_not_found(name_str, out_err),
     };
 
     let i64_col = match col.i64() {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' is not Int64", name_str));
-            return BASIS_ERR_TYPE_MISMATCH;
-        }
+        Err(_) => return fail_type_mismatch(name_str, "Int64", col.dtype(), out_err),
     };
 
     let len = i64_col.len();
-    let mut data: Vec<i64> = Vec::with_capacity(len);
-
-    for opt_val in i64_col.iter() {
-        data.push(opt_val.unwrap_or(0));
-    }
+    let data: Vec<i64> = i64_col.iter().map(|v| v.unwrap_or(0)).collect();
 
     let mut boxed = data.into_boxed_slice();
     let ptr = boxed.as_mut_ptr();
@@ -242,23 +561,40 @@ pub extern "C" fn basis_int64_column_free(col: *mut Int64Column) {
     }
 }
 
-/// Get a Float64 column by name.
-/// The caller must free the returned data with `basis_float64_column_free`.
+/// Get an Int64 column by name, with explicit null tracking via a validity
+/// bitmap (unlike `basis_df_get_int64_column`, which reports nulls as `0`).
+/// The caller must free the returned data with
+/// `basis_int64_column_nullable_free`.
 #[no_mangle]
-pub extern "C" fn basis_df_get_float64_column(
+pub extern "C" fn basis_df_get_int64_column_nullable(
     df: *const BasisDataFrame,
     name: *const c_char,
-    out: *mut Float64Column,
+    out: *mut Int64ColumnNullable,
+) -> c_int {
+    basis_df_get_int64_column_nullable_ex(df, name, out, ptr::null_mut())
+}
+
+/// Same as `basis_df_get_int64_column_nullable`, but takes an `out_err` to
+/// receive a structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_int64_column_nullable_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut Int64ColumnNullable,
+    out_err: *mut *mut BasisError,
 ) -> c_int {
     if df.is_null() || name.is_null() || out.is_null() {
-        set_error("null pointer");
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
         return BASIS_ERR_NULL_PTR;
     }
 
     let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_error("invalid UTF-8 in column name");
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
             return BASIS_ERR_INVALID_UTF8;
         }
     };
@@ -267,26 +603,17 @@ pub extern "C" fn basis_df_get_float64_column(
 
     let col = match df_ref.column(name_str) {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' not found", name_str));
-            return BASIS_ERR_COLUMN_NOT_FOUND;
-        }
+        Err(_) => return fail_column_not_found(name_str, out_err),
     };
 
-    let f64_col = match col.f64() {
+    let i64_col = match col.i64() {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' is not Float64", name_str));
-            return BASIS_ERR_TYPE_MISMATCH;
-        }
+        Err(_) => return fail_type_mismatch(name_str, "Int64", col.dtype(), out_err),
     };
 
-    let len = f64_col.len();
-    let mut data: Vec<f64> = Vec::with_capacity(len);
-
-    for opt_val in f64_col.iter() {
-        data.push(opt_val.unwrap_or(0.0));
-    }
+    let len = i64_col.len();
+    let (validity, null_count, values) = build_validity_bitmap(i64_col.iter(), len);
+    let data: Vec<i64> = values.into_iter().map(|v| v.unwrap_or(0)).collect();
 
     let mut boxed = data.into_boxed_slice();
     let ptr = boxed.as_mut_ptr();
@@ -295,19 +622,24 @@ pub extern "C" fn basis_df_get_float64_column(
     unsafe {
         (*out).data = ptr;
         (*out).len = len;
+        (*out).validity = validity;
+        (*out).null_count = null_count;
     }
 
     BASIS_OK
 }
 
-/// Free a Float64Column's data.
+/// Free an Int64ColumnNullable's data.
 #[no_mangle]
-pub extern "C" fn basis_float64_column_free(col: *mut Float64Column) {
+pub extern "C" fn basis_int64_column_nullable_free(col: *mut Int64ColumnNullable) {
     if col.is_null() {
         return;
     }
     unsafe {
         let col_ref = &mut *col;
+        free_validity_bitmap(col_ref.validity, col_ref.len);
+        col_ref.validity = ptr::null_mut();
+        col_ref.null_count = 0;
         if !col_ref.data.is_null() && col_ref.len > 0 {
             drop(Vec::from_raw_parts(col_ref.data, col_ref.len, col_ref.len));
             col_ref.data = ptr::null_mut();
@@ -316,23 +648,38 @@ pub extern "C" fn basis_float64_column_free(col: *mut Float64Column) {
     }
 }
 
-/// Get a String column by name.
-/// The caller must free the returned data with `basis_string_column_free`.
+/// Get a Float64 column by name.
+/// The caller must free the returned data with `basis_float64_column_free`.
 #[no_mangle]
-pub extern "C" fn basis_df_get_string_column(
+pub extern "C" fn basis_df_get_float64_column(
     df: *const BasisDataFrame,
     name: *const c_char,
-    out: *mut StringColumn,
+    out: *mut Float64Column,
+) -> c_int {
+    basis_df_get_float64_column_ex(df, name, out, ptr::null_mut())
+}
+
+/// Same as `basis_df_get_float64_column`, but takes an `out_err` to receive
+/// a structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_float64_column_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut Float64Column,
+    out_err: *mut *mut BasisError,
 ) -> c_int {
     if df.is_null() || name.is_null() || out.is_null() {
-        set_error("null pointer");
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
         return BASIS_ERR_NULL_PTR;
     }
 
     let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_error("invalid UTF-8 in column name");
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
             return BASIS_ERR_INVALID_UTF8;
         }
     };
@@ -341,30 +688,16 @@ pub extern "C" fn basis_df_get_string_column(
 
     let col = match df_ref.column(name_str) {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' not found", name_str));
-            return BASIS_ERR_COLUMN_NOT_FOUND;
-        }
+        Err(_) => return fail_column_not_found(name_str, out_err),
     };
 
-    let str_col = match col.str() {
+    let f64_col = match col.f64() {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' is not String", name_str));
-            return BASIS_ERR_TYPE_MISMATCH;
-        }
+        Err(_) => return fail_type_mismatch(name_str, "Float64", col.dtype(), out_err),
     };
 
-    let len = str_col.len();
-    let mut data: Vec<*mut c_char> = Vec::with_capacity(len);
-
-    for opt_val in str_col.iter() {
-        let c_str = match opt_val {
-            Some(s) => CString::new(s).unwrap_or_default().into_raw(),
-            None => CString::new("").unwrap().into_raw(),
-        };
-        data.push(c_str);
-    }
+    let len = f64_col.len();
+    let data: Vec<f64> = f64_col.iter().map(|v| v.unwrap_or(0.0)).collect();
 
     let mut boxed = data.into_boxed_slice();
     let ptr = boxed.as_mut_ptr();
@@ -378,76 +711,348 @@ pub extern "C" fn basis_df_get_string_column(
     BASIS_OK
 }
 
-/// Free a StringColumn's data.
+/// Free a Float64Column's data.
 #[no_mangle]
-pub extern "C" fn basis_string_column_free(col: *mut StringColumn) {
+pub extern "C" fn basis_float64_column_free(col: *mut Float64Column) {
     if col.is_null() {
         return;
     }
     unsafe {
         let col_ref = &mut *col;
         if !col_ref.data.is_null() && col_ref.len > 0 {
-            let strings = Vec::from_raw_parts(col_ref.data, col_ref.len, col_ref.len);
-            for s in strings {
-                if !s.is_null() {
-                    drop(CString::from_raw(s));
-                }
-            }
+            drop(Vec::from_raw_parts(col_ref.data, col_ref.len, col_ref.len));
             col_ref.data = ptr::null_mut();
             col_ref.len = 0;
         }
     }
 }
 
-/// Add an Int64 column to the DataFrame.
+/// Get a Float64 column by name, with explicit null tracking via a validity
+/// bitmap (unlike `basis_df_get_float64_column`, which reports nulls as
+/// `0.0`). The caller must free the returned data with
+/// `basis_float64_column_nullable_free`.
 #[no_mangle]
-pub extern "C" fn basis_df_add_int64_column(
-    df: *mut BasisDataFrame,
+pub extern "C" fn basis_df_get_float64_column_nullable(
+    df: *const BasisDataFrame,
     name: *const c_char,
-    data: *const i64,
-    len: size_t,
+    out: *mut Float64ColumnNullable,
 ) -> c_int {
-    if df.is_null() || name.is_null() || (data.is_null() && len > 0) {
-        set_error("null pointer");
+    basis_df_get_float64_column_nullable_ex(df, name, out, ptr::null_mut())
+}
+
+/// Same as `basis_df_get_float64_column_nullable`, but takes an `out_err` to
+/// receive a structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_float64_column_nullable_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut Float64ColumnNullable,
+    out_err: *mut *mut BasisError,
+) -> c_int {
+    if df.is_null() || name.is_null() || out.is_null() {
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
         return BASIS_ERR_NULL_PTR;
     }
 
     let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_error("invalid UTF-8 in column name");
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
             return BASIS_ERR_INVALID_UTF8;
         }
     };
 
-    let slice = if len > 0 {
-        unsafe { std::slice::from_raw_parts(data, len) }
-    } else {
-        &[]
+    let df_ref = unsafe { &(*df).inner };
+
+    let col = match df_ref.column(name_str) {
+        Ok(c) => c,
+        Err(_) => return fail_column_not_found(name_str, out_err),
     };
 
-    let series = Series::new(name_str.into(), slice);
-    let df_ref = unsafe { &mut (*df).inner };
+    let f64_col = match col.f64() {
+        Ok(c) => c,
+        Err(_) => return fail_type_mismatch(name_str, "Float64", col.dtype(), out_err),
+    };
 
-    match df_ref.with_column(series) {
-        Ok(_) => BASIS_OK,
-        Err(e) => {
-            set_error(&e.to_string());
-            BASIS_ERR_POLARS
+    let len = f64_col.len();
+    let (validity, null_count, values) = build_validity_bitmap(f64_col.iter(), len);
+    let data: Vec<f64> = values.into_iter().map(|v| v.unwrap_or(0.0)).collect();
+
+    let mut boxed = data.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+
+    unsafe {
+        (*out).data = ptr;
+        (*out).len = len;
+        (*out).validity = validity;
+        (*out).null_count = null_count;
+    }
+
+    BASIS_OK
+}
+
+/// Free a Float64ColumnNullable's data.
+#[no_mangle]
+pub extern "C" fn basis_float64_column_nullable_free(col: *mut Float64ColumnNullable) {
+    if col.is_null() {
+        return;
+    }
+    unsafe {
+        let col_ref = &mut *col;
+        free_validity_bitmap(col_ref.validity, col_ref.len);
+        col_ref.validity = ptr::null_mut();
+        col_ref.null_count = 0;
+        if !col_ref.data.is_null() && col_ref.len > 0 {
+            drop(Vec::from_raw_parts(col_ref.data, col_ref.len, col_ref.len));
+            col_ref.data = ptr::null_mut();
+            col_ref.len = 0;
         }
     }
 }
 
-/// Add a Float64 column to the DataFrame.
+/// Get a String column by name.
+/// The caller must free the returned data with `basis_string_column_free`.
 #[no_mangle]
-pub extern "C" fn basis_df_add_float64_column(
-    df: *mut BasisDataFrame,
+pub extern "C" fn basis_df_get_string_column(
+    df: *const BasisDataFrame,
     name: *const c_char,
-    data: *const f64,
-    len: size_t,
+    out: *mut StringColumn,
 ) -> c_int {
-    if df.is_null() || name.is_null() || (data.is_null() && len > 0) {
-        set_error("null pointer");
+    basis_df_get_string_column_ex(df, name, out, ptr::null_mut())
+}
+
+/// Same as `basis_df_get_string_column`, but takes an `out_err` to receive a
+/// structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_string_column_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut StringColumn,
+    out_err: *mut *mut BasisError,
+) -> c_int {
+    if df.is_null() || name.is_null() || out.is_null() {
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
+        return BASIS_ERR_NULL_PTR;
+    }
+
+    let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
+            return BASIS_ERR_INVALID_UTF8;
+        }
+    };
+
+    let df_ref = unsafe { &(*df).inner };
+
+    let col = match df_ref.column(name_str) {
+        Ok(c) => c,
+        Err(_) => return fail_column_not_found(name_str, out_err),
+    };
+
+    let str_col = match col.str() {
+        Ok(c) => c,
+        Err(_) => return fail_type_mismatch(name_str, "String", col.dtype(), out_err),
+    };
+
+    let len = str_col.len();
+
+    let data: Vec<*mut c_char> = str_col
+        .iter()
+        .map(|opt_val| match opt_val {
+            Some(s) => CString::new(s).unwrap_or_default().into_raw(),
+            None => CString::new("").unwrap().into_raw(),
+        })
+        .collect();
+
+    let mut boxed = data.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+
+    unsafe {
+        (*out).data = ptr;
+        (*out).len = len;
+    }
+
+    BASIS_OK
+}
+
+/// Free a StringColumn's data.
+#[no_mangle]
+pub extern "C" fn basis_string_column_free(col: *mut StringColumn) {
+    if col.is_null() {
+        return;
+    }
+    unsafe {
+        let col_ref = &mut *col;
+        if !col_ref.data.is_null() && col_ref.len > 0 {
+            let strings = Vec::from_raw_parts(col_ref.data, col_ref.len, col_ref.len);
+            for s in strings {
+                if !s.is_null() {
+                    drop(CString::from_raw(s));
+                }
+            }
+            col_ref.data = ptr::null_mut();
+            col_ref.len = 0;
+        }
+    }
+}
+
+/// Get a String column by name, with explicit null tracking via a validity
+/// bitmap (unlike `basis_df_get_string_column`, which reports nulls as an
+/// empty string). The caller must free the returned data with
+/// `basis_string_column_nullable_free`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_string_column_nullable(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut StringColumnNullable,
+) -> c_int {
+    basis_df_get_string_column_nullable_ex(df, name, out, ptr::null_mut())
+}
+
+/// Same as `basis_df_get_string_column_nullable`, but takes an `out_err` to
+/// receive a structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_string_column_nullable_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut StringColumnNullable,
+    out_err: *mut *mut BasisError,
+) -> c_int {
+    if df.is_null() || name.is_null() || out.is_null() {
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
+        return BASIS_ERR_NULL_PTR;
+    }
+
+    let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
+            return BASIS_ERR_INVALID_UTF8;
+        }
+    };
+
+    let df_ref = unsafe { &(*df).inner };
+
+    let col = match df_ref.column(name_str) {
+        Ok(c) => c,
+        Err(_) => return fail_column_not_found(name_str, out_err),
+    };
+
+    let str_col = match col.str() {
+        Ok(c) => c,
+        Err(_) => return fail_type_mismatch(name_str, "String", col.dtype(), out_err),
+    };
+
+    let len = str_col.len();
+    let (validity, null_count, values) = build_validity_bitmap(str_col.iter(), len);
+
+    let data: Vec<*mut c_char> = values
+        .into_iter()
+        .map(|opt_val| match opt_val {
+            Some(s) => CString::new(s).unwrap_or_default().into_raw(),
+            None => CString::new("").unwrap().into_raw(),
+        })
+        .collect();
+
+    let mut boxed = data.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+
+    unsafe {
+        (*out).data = ptr;
+        (*out).len = len;
+        (*out).validity = validity;
+        (*out).null_count = null_count;
+    }
+
+    BASIS_OK
+}
+
+/// Free a StringColumnNullable's data.
+#[no_mangle]
+pub extern "C" fn basis_string_column_nullable_free(col: *mut StringColumnNullable) {
+    if col.is_null() {
+        return;
+    }
+    unsafe {
+        let col_ref = &mut *col;
+        free_validity_bitmap(col_ref.validity, col_ref.len);
+        col_ref.validity = ptr::null_mut();
+        col_ref.null_count = 0;
+        if !col_ref.data.is_null() && col_ref.len > 0 {
+            let strings = Vec::from_raw_parts(col_ref.data, col_ref.len, col_ref.len);
+            for s in strings {
+                if !s.is_null() {
+                    drop(CString::from_raw(s));
+                }
+            }
+            col_ref.data = ptr::null_mut();
+            col_ref.len = 0;
+        }
+    }
+}
+
+/// Add an Int64 column to the DataFrame.
+#[no_mangle]
+pub extern "C" fn basis_df_add_int64_column(
+    df: *mut BasisDataFrame,
+    name: *const c_char,
+    data: *const i64,
+    len: size_t,
+) -> c_int {
+    if df.is_null() || name.is_null() || (data.is_null() && len > 0) {
+        set_error("null pointer");
+        return BASIS_ERR_NULL_PTR;
+    }
+
+    let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error("invalid UTF-8 in column name");
+            return BASIS_ERR_INVALID_UTF8;
+        }
+    };
+
+    let slice = if len > 0 {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    } else {
+        &[]
+    };
+
+    let series = Series::new(name_str.into(), slice);
+    let df_ref = unsafe { &mut (*df).inner };
+
+    match df_ref.with_column(series) {
+        Ok(_) => BASIS_OK,
+        Err(e) => {
+            set_error(&e.to_string());
+            BASIS_ERR_POLARS
+        }
+    }
+}
+
+/// Add a Float64 column to the DataFrame.
+#[no_mangle]
+pub extern "C" fn basis_df_add_float64_column(
+    df: *mut BasisDataFrame,
+    name: *const c_char,
+    data: *const f64,
+    len: size_t,
+) -> c_int {
+    if df.is_null() || name.is_null() || (data.is_null() && len > 0) {
+        set_error("null pointer");
         return BASIS_ERR_NULL_PTR;
     }
 
@@ -573,16 +1178,31 @@ pub extern "C" fn basis_df_get_bool_column(
     df: *const BasisDataFrame,
     name: *const c_char,
     out: *mut BoolColumn,
+) -> c_int {
+    basis_df_get_bool_column_ex(df, name, out, ptr::null_mut())
+}
+
+/// Same as `basis_df_get_bool_column`, but takes an `out_err` to receive a
+/// structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_bool_column_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut BoolColumn,
+    out_err: *mut *mut BasisError,
 ) -> c_int {
     if df.is_null() || name.is_null() || out.is_null() {
-        set_error("null pointer");
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
         return BASIS_ERR_NULL_PTR;
     }
 
     let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_error("invalid UTF-8 in column name");
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
             return BASIS_ERR_INVALID_UTF8;
         }
     };
@@ -591,26 +1211,16 @@ pub extern "C" fn basis_df_get_bool_column(
 
     let col = match df_ref.column(name_str) {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' not found", name_str));
-            return BASIS_ERR_COLUMN_NOT_FOUND;
-        }
+        Err(_) => return fail_column_not_found(name_str, out_err),
     };
 
     let bool_col = match col.bool() {
         Ok(c) => c,
-        Err(_) => {
-            set_error(&format!("column '{}' is not Bool", name_str));
-            return BASIS_ERR_TYPE_MISMATCH;
-        }
+        Err(_) => return fail_type_mismatch(name_str, "Bool", col.dtype(), out_err),
     };
 
     let len = bool_col.len();
-    let mut data: Vec<bool> = Vec::with_capacity(len);
-
-    for opt_val in bool_col.iter() {
-        data.push(opt_val.unwrap_or(false));
-    }
+    let data: Vec<bool> = bool_col.iter().map(|v| v.unwrap_or(false)).collect();
 
     let mut boxed = data.into_boxed_slice();
     let ptr = boxed.as_mut_ptr();
@@ -639,3 +1249,614 @@ pub extern "C" fn basis_bool_column_free(col: *mut BoolColumn) {
         }
     }
 }
+
+/// Get a Bool column by name, with explicit null tracking via a validity
+/// bitmap (unlike `basis_df_get_bool_column`, which reports nulls as
+/// `false`). The caller must free the returned data with
+/// `basis_bool_column_nullable_free`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_bool_column_nullable(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut BoolColumnNullable,
+) -> c_int {
+    basis_df_get_bool_column_nullable_ex(df, name, out, ptr::null_mut())
+}
+
+/// Same as `basis_df_get_bool_column_nullable`, but takes an `out_err` to
+/// receive a structured `BasisError` on failure instead of only updating
+/// `basis_get_last_error()`.
+#[no_mangle]
+pub extern "C" fn basis_df_get_bool_column_nullable_ex(
+    df: *const BasisDataFrame,
+    name: *const c_char,
+    out: *mut BoolColumnNullable,
+    out_err: *mut *mut BasisError,
+) -> c_int {
+    if df.is_null() || name.is_null() || out.is_null() {
+        set_error_detailed(BasisErrorKind::NullPointer, "null pointer", None, None, None);
+        populate_out_err(out_err);
+        return BASIS_ERR_NULL_PTR;
+    }
+
+    let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error_detailed(BasisErrorKind::InvalidUtf8, "invalid UTF-8 in column name", None, None, None);
+            populate_out_err(out_err);
+            return BASIS_ERR_INVALID_UTF8;
+        }
+    };
+
+    let df_ref = unsafe { &(*df).inner };
+
+    let col = match df_ref.column(name_str) {
+        Ok(c) => c,
+        Err(_) => return fail_column_not_found(name_str, out_err),
+    };
+
+    let bool_col = match col.bool() {
+        Ok(c) => c,
+        Err(_) => return fail_type_mismatch(name_str, "Bool", col.dtype(), out_err),
+    };
+
+    let len = bool_col.len();
+    let (validity, null_count, values) = build_validity_bitmap(bool_col.iter(), len);
+    let data: Vec<bool> = values.into_iter().map(|v| v.unwrap_or(false)).collect();
+
+    let mut boxed = data.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+
+    unsafe {
+        (*out).data = ptr;
+        (*out).len = len;
+        (*out).validity = validity;
+        (*out).null_count = null_count;
+    }
+
+    BASIS_OK
+}
+
+/// Free a BoolColumnNullable's data.
+#[no_mangle]
+pub extern "C" fn basis_bool_column_nullable_free(col: *mut BoolColumnNullable) {
+    if col.is_null() {
+        return;
+    }
+    unsafe {
+        let col_ref = &mut *col;
+        free_validity_bitmap(col_ref.validity, col_ref.len);
+        col_ref.validity = ptr::null_mut();
+        col_ref.null_count = 0;
+        if !col_ref.data.is_null() && col_ref.len > 0 {
+            drop(Vec::from_raw_parts(col_ref.data, col_ref.len, col_ref.len));
+            col_ref.data = ptr::null_mut();
+            col_ref.len = 0;
+        }
+    }
+}
+
+// ==================== Arrow C Data Interface export ====================
+//
+// These entry points fill the standard Arrow C Data Interface structs
+// (https://arrow.apache.org/docs/format/CDataInterface.html) directly from
+// the Polars/Arrow chunks backing a column, so a C/C++/Python caller can
+// consume the data without going through the copying getters above.
+
+/// Standard Arrow C Data Interface `ArrowSchema` struct. Layout-compatible
+/// with the definition in `abi.h` shipped by Arrow implementations.
+pub type ArrowSchema = arrow_ffi::ArrowSchema;
+
+/// Standard Arrow C Data Interface `ArrowArray` struct. Layout-compatible
+/// with the definition in `abi.h` shipped by Arrow implementations.
+pub type ArrowArray = arrow_ffi::ArrowArray;
+
+/// Export a DataFrame column as Arrow C Data Interface structs, without
+/// copying the underlying buffers.
+///
+/// `out_schema` and `out_array` must point at caller-allocated, zeroed
+/// `ArrowSchema`/`ArrowArray` storage. On success each carries a `release`
+/// callback that the consumer MUST invoke exactly once when done; the
+/// callback keeps the backing Rust allocation alive until then.
+///
+/// The column is rechunked first so the export always describes a single
+/// contiguous Arrow array rather than one array per chunk.
+#[no_mangle]
+pub extern "C" fn basis_df_export_column_arrow(
+    df: *mut BasisDataFrame,
+    name: *const c_char,
+    out_schema: *mut ArrowSchema,
+    out_array: *mut ArrowArray,
+) -> c_int {
+    if df.is_null() || name.is_null() || out_schema.is_null() || out_array.is_null() {
+        set_error("null pointer");
+        return BASIS_ERR_NULL_PTR;
+    }
+
+    let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error("invalid UTF-8 in column name");
+            return BASIS_ERR_INVALID_UTF8;
+        }
+    };
+
+    let df_ref = unsafe { &mut (*df).inner };
+
+    let series = match df_ref.column(name_str) {
+        Ok(c) => c.as_materialized_series().clone(),
+        Err(_) => {
+            set_error(&format!("column '{}' not found", name_str));
+            return BASIS_ERR_COLUMN_NOT_FOUND;
+        }
+    };
+
+    // Rechunk so there is exactly one Arrow array to export.
+    let series = series.rechunk();
+    let field = series.field().to_arrow(CompatLevel::newest());
+    let chunk = series.to_arrow(0, CompatLevel::newest());
+
+    unsafe {
+        *out_schema = arrow_ffi::export_field_to_c(&field);
+        *out_array = arrow_ffi::export_array_to_c(chunk);
+    }
+
+    BASIS_OK
+}
+
+/// Free private data and _types_ held by an `ArrowSchema`, invoking its
+/// `release` callback if present. Safe to call on an already-released or
+/// zeroed struct.
+#[no_mangle]
+pub extern "C" fn basis_arrow_schema_release(schema: *mut ArrowSchema) {
+    if schema.is_null() {
+        return;
+    }
+    unsafe {
+        if let Some(release) = (*schema).release {
+            release(schema);
+        }
+    }
+}
+
+/// Free private data held by an `ArrowArray`, invoking its `release`
+/// callback if present. Safe to call on an already-released or zeroed
+/// struct.
+#[no_mangle]
+pub extern "C" fn basis_arrow_array_release(array: *mut ArrowArray) {
+    if array.is_null() {
+        return;
+    }
+    unsafe {
+        if let Some(release) = (*array).release {
+            release(array);
+        }
+    }
+}
+
+// ==================== Out-of-core streaming reader ====================
+//
+// For files that don't fit in memory, this cursor pulls row groups
+// forward-only from a persistent `BatchedParquetReader` (the same mechanism
+// `BatchedParquetWriter` uses on the write side) instead of re-scanning the
+// file from the start on every `basis_parquet_next_batch` call.
+
+/// Opaque cursor over bounded slices of a Parquet file. The underlying
+/// `BatchedParquetReader` is opened lazily on the first
+/// `basis_parquet_next_batch` call, once any `basis_parquet_stream_select`
+/// projection is known.
+pub struct BasisParquetStream {
+    path: String,
+    columns: Option<Vec<String>>,
+    reader: Option<polars::io::parquet::read::BatchedParquetReader>,
+    pending: Option<DataFrame>,
+    exhausted: bool,
+}
+
+/// Open a Parquet file for streaming, bounded-memory reads.
+/// Returns NULL on error. Use `basis_get_last_error()` for details.
+#[no_mangle]
+pub extern "C" fn basis_parquet_open(path: *const c_char) -> *mut BasisParquetStream {
+    if path.is_null() {
+        set_error("path is null");
+        return ptr::null_mut();
+    }
+
+    let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error("invalid UTF-8 in path");
+            return ptr::null_mut();
+        }
+    };
+
+    if !std::path::Path::new(path_str).exists() {
+        set_error(&format!("file not found: {}", path_str));
+        return ptr::null_mut();
+    }
+
+    Box::into_raw(Box::new(BasisParquetStream {
+        path: path_str.to_string(),
+        columns: None,
+        reader: None,
+        pending: None,
+        exhausted: false,
+    }))
+}
+
+/// Restrict the stream to a column projection. Mirrors the `with_columns`
+/// projection pushdown used by `ParquetReader::scan`. Must be called before
+/// the first `basis_parquet_next_batch`.
+#[no_mangle]
+pub extern "C" fn basis_parquet_stream_select(
+    stream: *mut BasisParquetStream,
+    names: *const *const c_char,
+    n: size_t,
+) -> c_int {
+    if stream.is_null() || (names.is_null() && n > 0) {
+        set_error("null pointer");
+        return BASIS_ERR_NULL_PTR;
+    }
+
+    let mut columns = Vec::with_capacity(n);
+    for i in 0..n {
+        let name_ptr = unsafe { *names.add(i) };
+        if name_ptr.is_null() {
+            set_error("null column name");
+            return BASIS_ERR_NULL_PTR;
+        }
+        match unsafe { CStr::from_ptr(name_ptr) }.to_str() {
+            Ok(s) => columns.push(s.to_string()),
+            Err(_) => {
+                set_error("invalid UTF-8 in column name");
+                return BASIS_ERR_INVALID_UTF8;
+            }
+        }
+    }
+
+    unsafe {
+        (*stream).columns = Some(columns);
+    }
+
+    BASIS_OK
+}
+
+/// Read the next batch of up to `max_rows` rows. Returns NULL at EOF or on
+/// error; use `basis_get_last_error()` to tell the two apart.
+#[no_mangle]
+pub extern "C" fn basis_parquet_next_batch(
+    stream: *mut BasisParquetStream,
+    max_rows: size_t,
+) -> *mut BasisDataFrame {
+    if stream.is_null() {
+        set_error("null pointer");
+        return ptr::null_mut();
+    }
+
+    let stream_ref = unsafe { &mut *stream };
+    if stream_ref.exhausted && stream_ref.pending.is_none() {
+        return ptr::null_mut();
+    }
+
+    if stream_ref.reader.is_none() {
+        let file = match std::fs::File::open(&stream_ref.path) {
+            Ok(f) => f,
+            Err(e) => {
+                set_error(&e.to_string());
+                return ptr::null_mut();
+            }
+        };
+        let mut pq_reader = polars::io::parquet::read::ParquetReader::new(file);
+        if let Some(cols) = &stream_ref.columns {
+            pq_reader = pq_reader.with_columns(Some(cols.clone()));
+        }
+        match pq_reader.batched(max_rows.max(1)) {
+            Ok(r) => stream_ref.reader = Some(r),
+            Err(e) => {
+                set_error(&e.to_string());
+                return ptr::null_mut();
+            }
+        }
+    }
+
+    while !stream_ref.exhausted
+        && stream_ref.pending.as_ref().map_or(0, |df| df.height()) < max_rows
+    {
+        let reader = stream_ref.reader.as_mut().expect("initialized above");
+        match reader.next_batches(1) {
+            Ok(Some(batches)) if !batches.is_empty() => {
+                let mut iter = batches.into_iter();
+                let mut group = iter.next().expect("checked non-empty above");
+                for batch in iter {
+                    if let Err(e) = group.vstack_mut(&batch) {
+                        set_error(&e.to_string());
+                        return ptr::null_mut();
+                    }
+                }
+                stream_ref.pending = Some(match stream_ref.pending.take() {
+                    Some(mut existing) => {
+                        if let Err(e) = existing.vstack_mut(&group) {
+                            set_error(&e.to_string());
+                            return ptr::null_mut();
+                        }
+                        existing
+                    }
+                    None => group,
+                });
+            }
+            Ok(_) => stream_ref.exhausted = true,
+            Err(e) => {
+                set_error(&e.to_string());
+                return ptr::null_mut();
+            }
+        }
+    }
+
+    let available = stream_ref.pending.as_ref().map_or(0, |df| df.height());
+    if available == 0 {
+        return ptr::null_mut();
+    }
+
+    let pending = stream_ref.pending.take().expect("available > 0 implies Some");
+    let take = max_rows.min(available);
+    let out = pending.slice(0, take);
+    let rest = pending.slice(take as i64, available - take);
+    stream_ref.pending = if rest.height() > 0 { Some(rest) } else { None };
+
+    Box::into_raw(Box::new(BasisDataFrame { inner: out }))
+}
+
+/// Free a streaming cursor.
+#[no_mangle]
+pub extern "C" fn basis_parquet_stream_free(stream: *mut BasisParquetStream) {
+    if !stream.is_null() {
+        unsafe {
+            drop(Box::from_raw(stream));
+        }
+    }
+}
+
+// ==================== Predicate pushdown / filtered scan ====================
+//
+// Translates a C-supplied comparison (or compound AND/OR of comparisons)
+// into a Polars lazy filter expression, so the predicate is pushed down
+// into the Parquet reader and row groups are skipped at scan time, the
+// same way `ParquetReader::scan()` already does for Rust callers.
+
+/// Comparison operator for a filter clause.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasisFilterOp {
+    Eq = 0,
+    Ne = 1,
+    Lt = 2,
+    Le = 3,
+    Gt = 4,
+    Ge = 5,
+}
+
+/// Logical connective used to combine multiple filter clauses.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasisLogicalOp {
+    And = 0,
+    Or = 1,
+}
+
+/// Discriminant for `BasisValue`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasisValueTag {
+    I64 = 0,
+    F64 = 1,
+    Bool = 2,
+    Str = 3,
+}
+
+/// Tagged union payload for a filter value.
+#[repr(C)]
+pub union BasisValueData {
+    pub i64_val: i64,
+    pub f64_val: f64,
+    pub bool_val: bool,
+    pub str_val: *const c_char,
+}
+
+/// A typed comparison value: `{ tag, data }` where `tag` selects the active
+/// field of `data`.
+#[repr(C)]
+pub struct BasisValue {
+    pub tag: BasisValueTag,
+    pub data: BasisValueData,
+}
+
+/// One `column <op> value` clause of a filtered scan.
+#[repr(C)]
+pub struct BasisFilterClause {
+    pub column: *const c_char,
+    pub op: BasisFilterOp,
+    pub value: BasisValue,
+}
+
+fn basis_value_to_lit(value: &BasisValue) -> Result<Expr, c_int> {
+    match value.tag {
+        BasisValueTag::I64 => Ok(lit(unsafe { value.data.i64_val })),
+        BasisValueTag::F64 => Ok(lit(unsafe { value.data.f64_val })),
+        BasisValueTag::Bool => Ok(lit(unsafe { value.data.bool_val })),
+        BasisValueTag::Str => {
+            let ptr = unsafe { value.data.str_val };
+            if ptr.is_null() {
+                set_error("null string value");
+                return Err(BASIS_ERR_NULL_PTR);
+            }
+            match unsafe { CStr::from_ptr(ptr) }.to_str() {
+                Ok(s) => Ok(lit(s.to_string())),
+                Err(_) => {
+                    set_error("invalid UTF-8 in filter value");
+                    Err(BASIS_ERR_INVALID_UTF8)
+                }
+            }
+        }
+    }
+}
+
+fn basis_filter_expr(column: &str, op: BasisFilterOp, value: Expr) -> Expr {
+    let c = col(column);
+    match op {
+        BasisFilterOp::Eq => c.eq(value),
+        BasisFilterOp::Ne => c.neq(value),
+        BasisFilterOp::Lt => c.lt(value),
+        BasisFilterOp::Le => c.lt_eq(value),
+        BasisFilterOp::Gt => c.gt(value),
+        BasisFilterOp::Ge => c.gt_eq(value),
+    }
+}
+
+/// Check that a clause's value tag is compatible with the schema's dtype
+/// for its column, returning `BASIS_ERR_TYPE_MISMATCH` if not.
+fn check_clause_type(schema: &Schema, column: &str, value: &BasisValue) -> c_int {
+    let Some(dtype) = schema.get(column) else {
+        set_error(&format!("column '{}' not found", column));
+        return BASIS_ERR_COLUMN_NOT_FOUND;
+    };
+
+    let compatible = matches!(
+        (dtype, value.tag),
+        (
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64,
+            BasisValueTag::I64
+        ) | (DataType::Float32 | DataType::Float64, BasisValueTag::F64)
+            | (DataType::Boolean, BasisValueTag::Bool)
+            | (DataType::String, BasisValueTag::Str)
+    );
+
+    if compatible {
+        BASIS_OK
+    } else {
+        set_error(&format!(
+            "type mismatch for column '{}': column is {:?}",
+            column, dtype
+        ));
+        BASIS_ERR_TYPE_MISMATCH
+    }
+}
+
+fn clause_to_expr(schema: &Schema, clause: &BasisFilterClause) -> Result<Expr, c_int> {
+    if clause.column.is_null() {
+        set_error("null column name");
+        return Err(BASIS_ERR_NULL_PTR);
+    }
+    let column = match unsafe { CStr::from_ptr(clause.column) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error("invalid UTF-8 in column name");
+            return Err(BASIS_ERR_INVALID_UTF8);
+        }
+    };
+
+    let status = check_clause_type(schema, column, &clause.value);
+    if status != BASIS_OK {
+        return Err(status);
+    }
+
+    let value_expr = basis_value_to_lit(&clause.value)?;
+    Ok(basis_filter_expr(column, clause.op, value_expr))
+}
+
+/// Scan a Parquet file with a single pushed-down predicate.
+/// Returns `BASIS_OK` and writes the resulting DataFrame handle to `out_df`
+/// on success, or a negative `BASIS_ERR_*` code on failure.
+#[no_mangle]
+pub extern "C" fn basis_parquet_scan_filter(
+    path: *const c_char,
+    column: *const c_char,
+    op: BasisFilterOp,
+    value: BasisValue,
+    out_df: *mut *mut BasisDataFrame,
+) -> c_int {
+    let clause = BasisFilterClause { column, op, value };
+    basis_parquet_scan_filter_many(path, &clause, 1, BasisLogicalOp::And, out_df)
+}
+
+/// Scan a Parquet file with `n_clauses` predicates combined by `connective`
+/// (AND/OR), pushed down into the lazy scan so row groups are skipped at
+/// scan time.
+#[no_mangle]
+pub extern "C" fn basis_parquet_scan_filter_many(
+    path: *const c_char,
+    clauses: *const BasisFilterClause,
+    n_clauses: size_t,
+    connective: BasisLogicalOp,
+    out_df: *mut *mut BasisDataFrame,
+) -> c_int {
+    if path.is_null() || (clauses.is_null() && n_clauses > 0) || out_df.is_null() {
+        set_error("null pointer");
+        return BASIS_ERR_NULL_PTR;
+    }
+
+    let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error("invalid UTF-8 in path");
+            return BASIS_ERR_INVALID_UTF8;
+        }
+    };
+
+    let lf = match ParquetReader::new(path_str).scan() {
+        Ok(lf) => lf,
+        Err(e) => {
+            set_error(&e.to_string());
+            return BASIS_ERR_IO;
+        }
+    };
+
+    let schema = match lf.clone().collect_schema() {
+        Ok(s) => s,
+        Err(e) => {
+            set_error(&e.to_string());
+            return BASIS_ERR_POLARS;
+        }
+    };
+
+    let mut exprs = Vec::with_capacity(n_clauses);
+    for i in 0..n_clauses {
+        let clause = unsafe { &*clauses.add(i) };
+        match clause_to_expr(&schema, clause) {
+            Ok(expr) => exprs.push(expr),
+            Err(code) => return code,
+        }
+    }
+
+    let Some(mut combined) = exprs.pop() else {
+        set_error("no filter clauses supplied");
+        return BASIS_ERR_NULL_PTR;
+    };
+    for expr in exprs {
+        combined = match connective {
+            BasisLogicalOp::And => combined.and(expr),
+            BasisLogicalOp::Or => combined.or(expr),
+        };
+    }
+
+    let df = match lf.filter(combined).collect() {
+        Ok(df) => df,
+        Err(e) => {
+            set_error(&e.to_string());
+            return BASIS_ERR_POLARS;
+        }
+    };
+
+    unsafe {
+        *out_df = Box::into_raw(Box::new(BasisDataFrame { inner: df }));
+    }
+
+    BASIS_OK
+}
@@ -8,5 +8,8 @@ pub mod basis;
 pub mod cxx_bridge;
 pub mod ffi;
 
+#[cfg(feature = "extern-ffi")]
+pub mod extern_ffi;
+
 // Re-export commonly used items
 pub use basis::parquet::{ParquetError, ParquetReader, ParquetWriter};